@@ -1,7 +1,9 @@
 //! bucket管理
 
 use qcos::acl::{AclHeader, BucketAcl};
+use qcos::bucket::{BucketStatus, CORSConfiguration, CORSRule, CorsMethod};
 use qcos::client::Client;
+use qcos::options::BucketOptions;
 use qcos::request::ErrNo;
 
 #[tokio::main]
@@ -19,6 +21,16 @@ async fn main() {
     } else {
         println!("{:?}", res.result);
     }
+    // 获取指定地域的bucket列表，无需指定bucket
+    let service = Client::service("your secrect id", "your secrect key");
+    match service.list_buckets("ap-guangzhou", None, "", 100).await {
+        Ok(res) => {
+            for bucket in res.buckets.bucket {
+                println!("{} {}", bucket.name, bucket.creation_date);
+            }
+        }
+        Err(e) => println!("{}", e.error_message),
+    }
     // 删除bucket
     let res = client.delete_bucket().await;
     if res.error_no == ErrNo::SUCCESS {
@@ -26,6 +38,16 @@ async fn main() {
     } else {
         println!("{:?}", res.result);
     }
+    // 清空并删除bucket，先dry run查看将要删除的内容
+    match client.delete_bucket_force("bucket-name", true).await {
+        Ok(report) => println!(
+            "{} versions, {} delete markers, {} uploads",
+            report.versions.len(),
+            report.delete_markers.len(),
+            report.uploads.len()
+        ),
+        Err(e) => println!("{}", e.error_message),
+    }
     // 创建bucket(无权限控制), 创建的bucket即上初始化传入的bucket-name
     let res = client.put_bucket(None).await;
     if res.error_no == ErrNo::SUCCESS {
@@ -42,6 +64,21 @@ async fn main() {
     } else {
         println!("{:?}", res.result);
     }
+    // 创建多AZ的bucket，已存在时视为成功
+    let mut options = BucketOptions::new();
+    options.insert_maz();
+    let res = client.ensure_bucket(None, &options).await;
+    if res.error_no == ErrNo::SUCCESS {
+        println!("SUCCESS");
+    } else {
+        println!("{:?}", res.error_response());
+    }
+    // 查询bucket状态
+    match client.bucket_status().await {
+        Ok(BucketStatus::Exists) => println!("exists"),
+        Ok(status) => println!("{:?}", status),
+        Err(e) => println!("{}", e),
+    }
     // 列出key以`abc`开头的文件
     let res = client.list_objects("abc", "", "", "", 0).await;
     if res.error_no == ErrNo::SUCCESS {
@@ -65,4 +102,59 @@ async fn main() {
     } else {
         println!("{}", res.error_message);
     }
+    // 允许浏览器直传，并通过预检请求验证规则
+    let mut rule = CORSRule::new(
+        &["https://www.example.com"],
+        &[CorsMethod::PUT, CorsMethod::POST],
+    );
+    rule.allowed_headers.push("*".to_string());
+    rule.expose_headers.push("ETag".to_string());
+    let res = client
+        .put_bucket_cors(&CORSConfiguration { rules: vec![rule] })
+        .await;
+    if res.error_no == ErrNo::SUCCESS {
+        let res = client
+            .options_object(
+                "Cargo.toml",
+                "https://www.example.com",
+                CorsMethod::PUT,
+                &["content-type"],
+            )
+            .await;
+        println!("{:?}", res.headers.get("access-control-allow-origin"));
+    } else {
+        println!("{}", res.error_message);
+    }
+    // 开启版本控制
+    let res = client.put_bucket_versioning(true).await;
+    if res.error_no == ErrNo::SUCCESS {
+        println!("SUCCESS");
+    } else {
+        println!("{}", res.error_message);
+    }
+    // 将被覆盖的Cargo.toml恢复为上一个版本
+    match client.list_object_versions("Cargo.toml", "", "", 0).await {
+        Ok(res) => {
+            if let Some(previous) = res
+                .version
+                .iter()
+                .find(|x| x.key == "Cargo.toml" && !x.is_latest)
+            {
+                match client
+                    .copy_object(
+                        "Cargo.toml",
+                        "Cargo.toml",
+                        Some(&previous.version_id),
+                        None,
+                        None,
+                    )
+                    .await
+                {
+                    Ok(res) => println!("restored, new version: {:?}", res.version_id),
+                    Err(e) => println!("{}", e.error_message),
+                }
+            }
+        }
+        Err(e) => println!("{}", e.error_message),
+    }
 }
@@ -5,6 +5,7 @@ use std::path::PathBuf;
 use qcos::acl::{AclHeader, ObjectAcl};
 use qcos::client::Client;
 use qcos::objects::mime;
+use qcos::options::ObjectOptions;
 use qcos::request::ErrNo;
 
 #[tokio::main]
@@ -103,6 +104,22 @@ async fn main() {
     } else {
         println!("[{}]: {}", res.error_no, res.error_message);
     }
+    // 乐观并发控制，仅当对象的ETag仍然是上次读取到的值时才覆盖
+    let etag = res.headers.get("etag").cloned().unwrap_or_default();
+    let mut options = ObjectOptions::new();
+    options.insert_if_match(&etag).unwrap();
+    let res = client
+        .put_object_binary_with_options(
+            std::fs::read(&file_path).unwrap(),
+            "Cargo.toml",
+            Some(mime::TEXT_PLAIN_UTF_8),
+            None,
+            options,
+        )
+        .await;
+    if res.error_no == ErrNo::PRECONDITION_FAILED {
+        println!("对象已被其他人修改");
+    }
     // 删除文件 test/Cargo.toml
     let res = client.delete_object("test/Cargo.toml").await;
     if res.error_no == ErrNo::SUCCESS {
@@ -131,6 +148,14 @@ async fn main() {
     } else {
         println!("[{}]: {}", res.error_no, res.error_message);
     }
+    // 清理7天前发起且未完成的分块上传
+    match client
+        .abort_stale_uploads("", chrono::Utc::now() - chrono::Duration::days(7))
+        .await
+    {
+        Ok(uploads) => println!("aborted {} uploads", uploads.len()),
+        Err(res) => println!("[{}]: {}", res.error_no, res.error_message),
+    }
     // 获取预签名下载URL
     let url = client.get_presigned_download_url("Cargo.toml", 3600);
     println!("full_url: {}", url);
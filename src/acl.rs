@@ -1,9 +1,21 @@
 //!访问控制列表（ACL）
 
 use std::collections::HashMap;
+use std::convert::Infallible;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::request::Owner;
 
 /// 对象的预设 ACL, 见[文档](https://cloud.tencent.com/document/product/436/30752#.E9.A2.84.E8.AE.BE.E7.9A.84-acl)
-#[derive(Debug, PartialEq)]
+/// # Examples
+/// ```
+/// use qcos::acl::ObjectAcl;
+/// assert_eq!("public-read".parse::<ObjectAcl>().unwrap(), ObjectAcl::PublicRead);
+/// assert_eq!(ObjectAcl::BucketOwnerRead.to_string(), "bucket-owner-read");
+/// assert_eq!("foo".parse::<ObjectAcl>().unwrap(), ObjectAcl::Unknown("foo".to_string()));
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
 pub enum ObjectAcl {
     /// 空描述，此时根据各级目录的显式设置及存储桶的设置来确定是否允许请求（默认）
     DEFAULT,
@@ -17,23 +29,69 @@ pub enum ObjectAcl {
     BucketOwnerRead,
     /// 创建者和存储桶拥有者都具备 FULL_CONTROL 权限
     BucketOwnerFullControl,
+    /// 未知的取值，保留原始字符串
+    Unknown(String),
+}
+
+impl Display for ObjectAcl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            ObjectAcl::DEFAULT => "default",
+            ObjectAcl::PRIVATE => "private",
+            ObjectAcl::PublicRead => "public-read",
+            ObjectAcl::BucketOwnerRead => "bucket-owner-read",
+            ObjectAcl::AuthenticatedRead => "authenticated-read",
+            ObjectAcl::BucketOwnerFullControl => "bucket-owner-full-control",
+            ObjectAcl::Unknown(s) => s,
+        };
+        f.write_str(s)
+    }
+}
+
+impl FromStr for ObjectAcl {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "default" => ObjectAcl::DEFAULT,
+            "private" => ObjectAcl::PRIVATE,
+            "public-read" => ObjectAcl::PublicRead,
+            "bucket-owner-read" => ObjectAcl::BucketOwnerRead,
+            "authenticated-read" => ObjectAcl::AuthenticatedRead,
+            "bucket-owner-full-control" => ObjectAcl::BucketOwnerFullControl,
+            _ => ObjectAcl::Unknown(s.to_string()),
+        })
+    }
 }
 
 impl From<ObjectAcl> for String {
     fn from(val: ObjectAcl) -> Self {
-        match val {
-            ObjectAcl::DEFAULT => String::from("default"),
-            ObjectAcl::PRIVATE => String::from("private"),
-            ObjectAcl::PublicRead => String::from("public-read"),
-            ObjectAcl::BucketOwnerRead => String::from("bucket-owner-read"),
-            ObjectAcl::AuthenticatedRead => String::from("authenticated-read"),
-            ObjectAcl::BucketOwnerFullControl => String::from("bucket-owner-full-control"),
-        }
+        val.to_string()
+    }
+}
+
+impl serde::Serialize for ObjectAcl {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ObjectAcl {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        // 未知的取值解析为`Unknown`，不会失败
+        Ok(s.parse().unwrap())
     }
 }
 
 /// 存储桶的预设 ACL
-#[derive(Debug, PartialEq)]
+/// # Examples
+/// ```
+/// use qcos::acl::BucketAcl;
+/// assert_eq!("public-read-write".parse::<BucketAcl>().unwrap(), BucketAcl::PublicReadWrite);
+/// assert_eq!(BucketAcl::PRIVATE.to_string(), "private");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
 pub enum BucketAcl {
     /// 创建者（主账号）具备 FULL_CONTROL 权限，其他人没有权限（默认）
     PRIVATE,
@@ -43,17 +101,291 @@ pub enum BucketAcl {
     PublicReadWrite,
     /// 创建者具备 FULL_CONTROL 权限，认证用户组具备 READ 权限
     AuthenticatedRead,
+    /// 未知的取值，保留原始字符串
+    Unknown(String),
+}
+
+impl Display for BucketAcl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            BucketAcl::PRIVATE => "private",
+            BucketAcl::PublicRead => "public-read",
+            BucketAcl::PublicReadWrite => "public-read-write",
+            BucketAcl::AuthenticatedRead => "authenticated-read",
+            BucketAcl::Unknown(s) => s,
+        };
+        f.write_str(s)
+    }
+}
+
+impl FromStr for BucketAcl {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "private" => BucketAcl::PRIVATE,
+            "public-read" => BucketAcl::PublicRead,
+            "public-read-write" => BucketAcl::PublicReadWrite,
+            "authenticated-read" => BucketAcl::AuthenticatedRead,
+            _ => BucketAcl::Unknown(s.to_string()),
+        })
+    }
 }
 
 impl From<BucketAcl> for String {
     fn from(value: BucketAcl) -> Self {
-        match value {
-            BucketAcl::PRIVATE => String::from("private"),
-            BucketAcl::PublicRead => String::from("public-read"),
-            BucketAcl::PublicReadWrite => String::from("public-read-write"),
-            BucketAcl::AuthenticatedRead => String::from("authenticated-read"),
+        value.to_string()
+    }
+}
+
+impl serde::Serialize for BucketAcl {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for BucketAcl {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        // 未知的取值解析为`Unknown`，不会失败
+        Ok(s.parse().unwrap())
+    }
+}
+
+/// 匿名用户组
+pub const ALL_USERS_URI: &str = "http://cam.qcloud.com/groups/global/AllUsers";
+
+/// 认证用户组
+pub const AUTHENTICATED_USERS_URI: &str = "http://cam.qcloud.com/groups/global/AuthenticatedUsers";
+
+const XSI_NAMESPACE: &str = "http://www.w3.org/2001/XMLSchema-instance";
+
+fn xsi_namespace() -> String {
+    XSI_NAMESPACE.to_string()
+}
+
+/// 被授权者的类型
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum GranteeType {
+    /// 主账号或子账号，通过`ID`指定
+    CanonicalUser,
+    /// 预设的用户组，通过`URI`指定
+    Group,
+}
+
+/// 被授权者
+/// # Examples
+/// ```
+/// use qcos::acl::Grantee;
+/// let grantee = Grantee::sub_user("100000000001", "100000000011");
+/// assert_eq!(grantee.id.as_deref(), Some("qcs::cam::uin/100000000001:uin/100000000011"));
+/// assert_eq!(grantee.to_string(), r#"id="qcs::cam::uin/100000000001:uin/100000000011""#);
+/// assert_eq!(Grantee::all_users().to_string(), r#"uri="http://cam.qcloud.com/groups/global/AllUsers""#);
+/// ```
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct Grantee {
+    #[serde(rename = "@xmlns:xsi", default = "xsi_namespace")]
+    xmlns_xsi: String,
+    // 反序列化时命名空间前缀会被去掉
+    #[serde(rename = "@xsi:type", alias = "@type")]
+    pub grantee_type: GranteeType,
+    #[serde(rename = "ID", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "DisplayName", skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(rename = "URI", skip_serializing_if = "Option::is_none")]
+    pub uri: Option<String>,
+}
+
+impl Grantee {
+    /// 通过完整的ID指定被授权者，如`qcs::cam::uin/100000000001:uin/100000000001`
+    pub fn canonical_user(id: &str) -> Self {
+        Self {
+            xmlns_xsi: xsi_namespace(),
+            grantee_type: GranteeType::CanonicalUser,
+            id: Some(id.to_string()),
+            display_name: None,
+            uri: None,
         }
     }
+
+    /// 主账号
+    pub fn user(uin: &str) -> Self {
+        Self::sub_user(uin, uin)
+    }
+
+    /// 主账号`owner_uin`下的子账号`sub_uin`
+    pub fn sub_user(owner_uin: &str, sub_uin: &str) -> Self {
+        Self::canonical_user(&format!("qcs::cam::uin/{}:uin/{}", owner_uin, sub_uin))
+    }
+
+    /// 通过URI指定用户组
+    pub fn group(uri: &str) -> Self {
+        Self {
+            xmlns_xsi: xsi_namespace(),
+            grantee_type: GranteeType::Group,
+            id: None,
+            display_name: None,
+            uri: Some(uri.to_string()),
+        }
+    }
+
+    /// 匿名用户组
+    pub fn all_users() -> Self {
+        Self::group(ALL_USERS_URI)
+    }
+
+    /// 认证用户组
+    pub fn authenticated_users() -> Self {
+        Self::group(AUTHENTICATED_USERS_URI)
+    }
+}
+
+/// 转为`x-cos-grant-*`请求头中的格式，如`id="qcs::cam::uin/100000000001:uin/100000000001"`
+impl Display for Grantee {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.grantee_type {
+            GranteeType::CanonicalUser => {
+                write!(f, "id=\"{}\"", self.id.as_deref().unwrap_or_default())
+            }
+            GranteeType::Group => write!(f, "uri=\"{}\"", self.uri.as_deref().unwrap_or_default()),
+        }
+    }
+}
+
+/// 解析`x-cos-grant-*`请求头格式的被授权者，格式不正确时返回错误
+/// # Examples
+/// ```
+/// use qcos::acl::Grantee;
+/// let grantee: Grantee = r#"id="100000000001""#.parse().unwrap();
+/// assert_eq!(grantee, Grantee::user("100000000001"));
+/// let grantee: Grantee = r#"id="qcs::cam::uin/100000000001:uin/100000000011""#.parse().unwrap();
+/// assert_eq!(grantee, Grantee::sub_user("100000000001", "100000000011"));
+/// assert!(r#"id=100000000001"#.parse::<Grantee>().is_err());
+/// assert!(r#"id="qcs::cam::uin/abc:uin/100000000001""#.parse::<Grantee>().is_err());
+/// ```
+impl FromStr for Grantee {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || format!("无法解析的被授权者: {}", s);
+        let (name, value) = s.trim().split_once('=').ok_or_else(error)?;
+        let value = value
+            .strip_prefix('"')
+            .and_then(|x| x.strip_suffix('"'))
+            .ok_or_else(error)?;
+        let is_uin = |x: &str| !x.is_empty() && x.chars().all(|c| c.is_ascii_digit());
+        match name {
+            "id" if is_uin(value) => Ok(Grantee::user(value)),
+            "id" => {
+                let (owner_uin, sub_uin) = value
+                    .strip_prefix("qcs::cam::uin/")
+                    .and_then(|x| x.split_once(":uin/"))
+                    .ok_or_else(error)?;
+                if !is_uin(owner_uin) || !is_uin(sub_uin) {
+                    return Err(error());
+                }
+                Ok(Grantee::sub_user(owner_uin, sub_uin))
+            }
+            "uri" if value.starts_with("http://") || value.starts_with("https://") => {
+                Ok(Grantee::group(value))
+            }
+            _ => Err(error()),
+        }
+    }
+}
+
+/// 将多个被授权者以半角逗号连接，用于`x-cos-grant-*`请求头
+/// # Examples
+/// ```
+/// use qcos::acl::{format_grantees, Grantee};
+/// let s = format_grantees(&[Grantee::user("100000000001"), Grantee::user("100000000002")]);
+/// assert_eq!(s, r#"id="qcs::cam::uin/100000000001:uin/100000000001",id="qcs::cam::uin/100000000002:uin/100000000002""#);
+/// ```
+pub fn format_grantees(grantees: &[Grantee]) -> String {
+    grantees
+        .iter()
+        .map(|x| x.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// 授予的权限
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Permission {
+    /// 完全控制
+    #[serde(rename = "FULL_CONTROL")]
+    FullControl,
+    /// 读取
+    READ,
+    /// 写入，仅对存储桶有效
+    WRITE,
+    /// 读取ACL
+    #[serde(rename = "READ_ACP")]
+    ReadAcp,
+    /// 写入ACL
+    #[serde(rename = "WRITE_ACP")]
+    WriteAcp,
+}
+
+/// 单条授权信息
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct Grant {
+    #[serde(rename = "Grantee")]
+    pub grantee: Grantee,
+    #[serde(rename = "Permission")]
+    pub permission: Permission,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Default)]
+pub struct AccessControlList {
+    #[serde(rename = "Grant", default)]
+    pub grant: Vec<Grant>,
+}
+
+/// 访问控制策略，对象及存储桶的ACL均使用此结构
+/// <https://cloud.tencent.com/document/product/436/7748>
+/// # Examples
+/// ```
+/// use qcos::acl::{AccessControlPolicy, Grantee, Permission};
+/// use qcos::request::Owner;
+/// use quick_xml::de::from_str;
+/// use quick_xml::se::to_string;
+/// let owner = Owner { id: "qcs::cam::uin/100000000001:uin/100000000001".to_string(), display_name: String::new() };
+/// let mut policy = AccessControlPolicy::new(owner);
+/// policy.grant(Grantee::all_users(), Permission::READ);
+/// let s = to_string(&policy).unwrap();
+/// assert!(s.contains(r#"<Grantee xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:type="Group"><URI>http://cam.qcloud.com/groups/global/AllUsers</URI></Grantee><Permission>READ</Permission>"#));
+/// assert_eq!(from_str::<AccessControlPolicy>(&s).unwrap(), policy);
+/// ```
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct AccessControlPolicy {
+    #[serde(rename = "Owner")]
+    pub owner: Owner,
+    #[serde(rename = "AccessControlList", default)]
+    pub access_control_list: AccessControlList,
+}
+
+impl AccessControlPolicy {
+    pub fn new(owner: Owner) -> Self {
+        Self {
+            owner,
+            access_control_list: AccessControlList::default(),
+        }
+    }
+
+    /// 添加一条授权
+    pub fn grant(&mut self, grantee: Grantee, permission: Permission) -> &mut Self {
+        self.access_control_list.grant.push(Grant {
+            grantee,
+            permission,
+        });
+        self
+    }
+
+    pub fn grants(&self) -> &[Grant] {
+        &self.access_control_list.grant
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -87,39 +419,34 @@ impl AclHeader {
         self
     }
 
-    /// 赋予被授权者读取对象(桶)的权限，格式为 id="\[OwnerUin\]"，
-    /// 例如 id="100000000001"，可使用半角逗号（,）分隔多组被授权者，例如id="100000000001",id="100000000002"
-    pub fn insert_x_cos_grant_read(&mut self, x_cos_grant_read: String) -> &mut Self {
-        self.headers
-            .insert("x-cos-grant-read".to_string(), x_cos_grant_read);
-        self
+    /// 赋予被授权者读取对象(桶)的权限，被授权者通过[`Grantee`]的方法构造，
+    /// 或从`id="100000000001"`格式的字符串解析，格式错误在发送请求前即可发现
+    /// # Examples
+    /// ```
+    /// use qcos::acl::{AclHeader, Grantee};
+    /// let mut acl_header = AclHeader::new();
+    /// let grantee: Grantee = r#"id="100000000002""#.parse().unwrap();
+    /// acl_header.insert_x_cos_grant_read(&[Grantee::user("100000000001"), grantee]);
+    /// assert_eq!(
+    ///     acl_header.get_headers()["x-cos-grant-read"],
+    ///     r#"id="qcs::cam::uin/100000000001:uin/100000000001",id="qcs::cam::uin/100000000002:uin/100000000002""#
+    /// );
+    /// ```
+    pub fn insert_x_cos_grant_read(&mut self, grantees: &[Grantee]) -> &mut Self {
+        self.insert_grantees("x-cos-grant-read", grantees)
     }
 
-    /// 赋予被授权者读取对象(桶)的访问控制列表（ACL）的权限，格式为 id="\[OwnerUin\]"，
-    /// 例如 id="100000000001"，可使用半角逗号（,）分隔多组被授权者，例如id="100000000001",id="100000000002"
-    pub fn insert_x_cos_grant_read_acp(&mut self, x_cos_grant_read_acp: String) -> &mut Self {
-        self.headers
-            .insert("x-cos-grant-read-acp".to_string(), x_cos_grant_read_acp);
-        self
+    /// 赋予被授权者读取对象(桶)的访问控制列表（ACL）的权限
+    pub fn insert_x_cos_grant_read_acp(&mut self, grantees: &[Grantee]) -> &mut Self {
+        self.insert_grantees("x-cos-grant-read-acp", grantees)
     }
-    /// 赋予被授权者写入对象(桶)的访问控制列表（ACL）的权限，格式为 id="\[OwnerUin\]"，
-    /// 例如 id="100000000001"，可使用半角逗号（,）分隔多组被授权者，例如id="100000000001",id="100000000002"
-    pub fn insert_x_cos_grant_write_acp(&mut self, x_cos_grant_write_acp: String) -> &mut Self {
-        self.headers
-            .insert("x-cos-grant-write-acp".to_string(), x_cos_grant_write_acp);
-        self
+    /// 赋予被授权者写入对象(桶)的访问控制列表（ACL）的权限
+    pub fn insert_x_cos_grant_write_acp(&mut self, grantees: &[Grantee]) -> &mut Self {
+        self.insert_grantees("x-cos-grant-write-acp", grantees)
     }
-    /// 赋予被授权者操作对象(桶)的所有权限，格式为 id="\[OwnerUin\]"，
-    /// 例如 id="100000000001"，可使用半角逗号（,）分隔多组被授权者，例如id="100000000001",id="100000000002"
-    pub fn insert_x_cos_grant_full_control(
-        &mut self,
-        x_cos_grant_full_control: String,
-    ) -> &mut Self {
-        self.headers.insert(
-            "x-cos-grant-full-control".to_string(),
-            x_cos_grant_full_control,
-        );
-        self
+    /// 赋予被授权者操作对象(桶)的所有权限
+    pub fn insert_x_cos_grant_full_control(&mut self, grantees: &[Grantee]) -> &mut Self {
+        self.insert_grantees("x-cos-grant-full-control", grantees)
     }
 
     /// 定义存储桶的访问控制列表（ACL）属性。枚举值请参见 ACL 概述 文档中存储桶的预设 ACL 部分，
@@ -130,11 +457,19 @@ impl AclHeader {
         self
     }
 
-    /// 赋予被授权者写入存储桶的权限，格式为 id="\[OwnerUin\]"，
-    /// 如 id="100000000001"，可使用半角逗号（,）分隔多组被授权者，如 id="100000000001",id="100000000002"
-    pub fn insert_bucket_x_cos_grant_write(&mut self, x_cos_grant_write: String) -> &mut Self {
-        self.headers
-            .insert("x-cos-grant-write".to_string(), x_cos_grant_write);
+    /// 赋予被授权者写入存储桶的权限
+    pub fn insert_bucket_x_cos_grant_write(&mut self, grantees: &[Grantee]) -> &mut Self {
+        self.insert_grantees("x-cos-grant-write", grantees)
+    }
+
+    /// 被授权者为空时移除该请求头，避免发送空的授权
+    fn insert_grantees(&mut self, key: &str, grantees: &[Grantee]) -> &mut Self {
+        if grantees.is_empty() {
+            self.headers.remove(key);
+        } else {
+            self.headers
+                .insert(key.to_string(), format_grantees(grantees));
+        }
         self
     }
 }
@@ -149,27 +484,95 @@ mod test {
         let mut acl_header = acl::AclHeader::new();
         acl_header
             .insert_bucket_x_cos_acl(acl::BucketAcl::PublicRead)
-            .insert_x_cos_grant_read("x-cos-grant-read".to_string())
-            .insert_x_cos_grant_read_acp("x_cos_grant_read_acp".to_string())
-            .insert_x_cos_grant_write_acp("x_cos_grant_write_acp".to_string())
-            .insert_bucket_x_cos_grant_write("x_cos_grant_write".to_string());
+            .insert_x_cos_grant_read(&[acl::Grantee::all_users()])
+            .insert_x_cos_grant_read_acp(&[acl::Grantee::user("100000000001")])
+            .insert_x_cos_grant_write_acp(&[acl::Grantee::sub_user("100000000001", "100000000011")])
+            .insert_x_cos_grant_full_control(&[acl::Grantee::user("100000000001")])
+            .insert_x_cos_grant_full_control(&[])
+            .insert_bucket_x_cos_grant_write(&[acl::Grantee::authenticated_users()]);
 
         assert_eq!(acl_header.headers["x-cos-acl"], "public-read".to_string());
         assert_eq!(
             acl_header.headers["x-cos-grant-read"],
-            "x-cos-grant-read".to_string()
+            r#"uri="http://cam.qcloud.com/groups/global/AllUsers""#.to_string()
         );
         assert_eq!(
             acl_header.headers["x-cos-grant-read-acp"],
-            "x_cos_grant_read_acp".to_string()
+            r#"id="qcs::cam::uin/100000000001:uin/100000000001""#.to_string()
         );
         assert_eq!(
             acl_header.headers["x-cos-grant-write-acp"],
-            "x_cos_grant_write_acp".to_string()
+            r#"id="qcs::cam::uin/100000000001:uin/100000000011""#.to_string()
         );
+        assert!(!acl_header.headers.contains_key("x-cos-grant-full-control"));
         assert_eq!(
             acl_header.headers["x-cos-grant-write"],
-            "x_cos_grant_write".to_string()
+            r#"uri="http://cam.qcloud.com/groups/global/AuthenticatedUsers""#.to_string()
         );
+        for s in [
+            "",
+            "id=",
+            r#"id="""#,
+            r#"uri="ftp://x""#,
+            r#"email="a@b.c""#,
+        ] {
+            assert!(s.parse::<acl::Grantee>().is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_access_control_policy() {
+        let xml = r#"<AccessControlPolicy>
+            <Owner>
+                <ID>qcs::cam::uin/100000000001:uin/100000000001</ID>
+                <DisplayName>qcs::cam::uin/100000000001:uin/100000000001</DisplayName>
+            </Owner>
+            <AccessControlList>
+                <Grant>
+                    <Grantee xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:type="CanonicalUser">
+                        <ID>qcs::cam::uin/100000000001:uin/100000000001</ID>
+                        <DisplayName>qcs::cam::uin/100000000001:uin/100000000001</DisplayName>
+                    </Grantee>
+                    <Permission>FULL_CONTROL</Permission>
+                </Grant>
+                <Grant>
+                    <Grantee xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:type="Group">
+                        <URI>http://cam.qcloud.com/groups/global/AllUsers</URI>
+                    </Grantee>
+                    <Permission>READ</Permission>
+                </Grant>
+            </AccessControlList>
+        </AccessControlPolicy>"#;
+        let policy: acl::AccessControlPolicy = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(
+            policy.owner.id,
+            "qcs::cam::uin/100000000001:uin/100000000001"
+        );
+        let grants = policy.grants();
+        assert_eq!(grants.len(), 2);
+        assert_eq!(grants[0].permission, acl::Permission::FullControl);
+        assert_eq!(
+            grants[0].grantee.grantee_type,
+            acl::GranteeType::CanonicalUser
+        );
+        assert_eq!(grants[0].grantee.id, acl::Grantee::user("100000000001").id);
+        assert_eq!(grants[1].permission, acl::Permission::READ);
+        assert_eq!(grants[1].grantee.uri, acl::Grantee::all_users().uri);
+    }
+
+    #[test]
+    fn test_acl_serde() {
+        for acl in [
+            acl::ObjectAcl::DEFAULT,
+            acl::ObjectAcl::BucketOwnerFullControl,
+            acl::ObjectAcl::Unknown("foo".to_string()),
+        ] {
+            let s = serde_json::to_string(&acl).unwrap();
+            assert_eq!(s, format!("\"{}\"", acl));
+            assert_eq!(serde_json::from_str::<acl::ObjectAcl>(&s).unwrap(), acl);
+        }
+        let acl: acl::BucketAcl = serde_json::from_str("\"public-read\"").unwrap();
+        assert_eq!(acl, acl::BucketAcl::PublicRead);
+        assert_eq!(String::from(acl), "public-read");
     }
 }
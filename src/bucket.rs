@@ -1,16 +1,563 @@
 //! bucket相关接口 方法见 [`crate::client::Client`#impl-Client]
 
+use crate::checksum::content_md5;
 use crate::client::Client;
 
-use crate::request::{Request, Response};
+use crate::request::{
+    ErrNo, ListVersionsResult, ObjectIdentifier, Request, Response, Upload,
+    VersioningConfiguration, VersioningStatus,
+};
+use reqwest::header::{HeaderName, HeaderValue, CONTENT_TYPE};
 use reqwest::Body;
 
-use crate::acl::AclHeader;
+use crate::acl::{AccessControlPolicy, AclHeader};
+use crate::lifecycle::RuleStatus;
+use crate::options::BucketOptions;
+use quick_xml::se::to_string;
 use std::collections::HashMap;
 
 // 为了兼容以前的版本
 pub struct Bucket;
 
+/// 跨域请求允许的方法
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum CorsMethod {
+    GET,
+    PUT,
+    POST,
+    DELETE,
+    HEAD,
+}
+
+impl std::fmt::Display for CorsMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self, f)
+    }
+}
+
+impl std::str::FromStr for CorsMethod {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "GET" => Ok(CorsMethod::GET),
+            "PUT" => Ok(CorsMethod::PUT),
+            "POST" => Ok(CorsMethod::POST),
+            "DELETE" => Ok(CorsMethod::DELETE),
+            "HEAD" => Ok(CorsMethod::HEAD),
+            _ => Err(format!("不支持的跨域请求方法: {}", s)),
+        }
+    }
+}
+
+// 按字符串序列化，否则quick-xml会把枚举值当作元素名
+impl serde::Serialize for CorsMethod {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for CorsMethod {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// 单条跨域访问规则
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Default)]
+pub struct CORSRule {
+    /// 规则ID
+    #[serde(rename = "ID", default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// 允许的来源，如`https://www.example.com`，支持通配符`*`
+    #[serde(rename = "AllowedOrigin", default)]
+    pub allowed_origins: Vec<String>,
+    /// 允许的方法
+    #[serde(rename = "AllowedMethod", default)]
+    pub allowed_methods: Vec<CorsMethod>,
+    /// 预检请求中`Access-Control-Request-Headers`允许携带的请求头，支持通配符`*`
+    #[serde(rename = "AllowedHeader", default)]
+    pub allowed_headers: Vec<String>,
+    /// 允许浏览器读取的响应头，如`ETag`
+    #[serde(rename = "ExposeHeader", default)]
+    pub expose_headers: Vec<String>,
+    /// 预检请求结果的缓存时间，单位为秒
+    #[serde(
+        rename = "MaxAgeSeconds",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub max_age_seconds: Option<u32>,
+}
+
+impl CORSRule {
+    pub fn new(allowed_origins: &[&str], allowed_methods: &[CorsMethod]) -> Self {
+        Self {
+            allowed_origins: allowed_origins.iter().map(|x| x.to_string()).collect(),
+            allowed_methods: allowed_methods.to_vec(),
+            ..Default::default()
+        }
+    }
+}
+
+/// 存储桶的跨域访问配置
+/// <https://cloud.tencent.com/document/product/436/8279>
+/// ```
+/// use qcos::bucket::{CORSConfiguration, CORSRule, CorsMethod};
+/// use quick_xml::de::from_str;
+/// use quick_xml::se::to_string;
+/// let mut rule = CORSRule::new(&["https://www.example.com"], &[CorsMethod::PUT, CorsMethod::GET]);
+/// rule.allowed_headers.push("*".to_string());
+/// rule.max_age_seconds = Some(600);
+/// let config = CORSConfiguration { rules: vec![rule] };
+/// let s = to_string(&config).unwrap();
+/// assert_eq!(s, "<CORSConfiguration><CORSRule><AllowedOrigin>https://www.example.com</AllowedOrigin><AllowedMethod>PUT</AllowedMethod><AllowedMethod>GET</AllowedMethod><AllowedHeader>*</AllowedHeader><MaxAgeSeconds>600</MaxAgeSeconds></CORSRule></CORSConfiguration>");
+/// assert_eq!(from_str::<CORSConfiguration>(&s).unwrap(), config);
+/// ```
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Default)]
+pub struct CORSConfiguration {
+    #[serde(rename = "CORSRule", default)]
+    pub rules: Vec<CORSRule>,
+}
+
+/// 服务端加密算法
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Copy)]
+pub enum SseAlgorithm {
+    /// SSE-COS，由COS托管密钥
+    AES256,
+    /// SSE-KMS，使用KMS管理的密钥
+    KMS,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct ApplyServerSideEncryptionByDefault {
+    #[serde(rename = "SSEAlgorithm")]
+    pub sse_algorithm: SseAlgorithm,
+    /// SSE-KMS时使用的密钥ID，不传表示使用默认密钥
+    #[serde(
+        rename = "KMSMasterKeyID",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub kms_master_key_id: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct ServerSideEncryptionRule {
+    #[serde(rename = "ApplyServerSideEncryptionByDefault")]
+    pub apply_server_side_encryption_by_default: ApplyServerSideEncryptionByDefault,
+}
+
+/// 存储桶的默认加密配置，上传未指定加密方式的对象时使用
+/// <https://cloud.tencent.com/document/product/436/40136>
+/// ```
+/// use qcos::bucket::{ServerSideEncryptionConfiguration, SseAlgorithm};
+/// use quick_xml::de::from_str;
+/// use quick_xml::se::to_string;
+/// let config = ServerSideEncryptionConfiguration::sse_kms(Some("key-id"));
+/// let s = to_string(&config).unwrap();
+/// assert_eq!(s, "<ServerSideEncryptionConfiguration><Rule><ApplyServerSideEncryptionByDefault><SSEAlgorithm>KMS</SSEAlgorithm><KMSMasterKeyID>key-id</KMSMasterKeyID></ApplyServerSideEncryptionByDefault></Rule></ServerSideEncryptionConfiguration>");
+/// assert_eq!(from_str::<ServerSideEncryptionConfiguration>(&s).unwrap(), config);
+/// assert_eq!(ServerSideEncryptionConfiguration::sse_cos().algorithm(), SseAlgorithm::AES256);
+/// ```
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct ServerSideEncryptionConfiguration {
+    #[serde(rename = "Rule")]
+    pub rule: ServerSideEncryptionRule,
+}
+
+impl ServerSideEncryptionConfiguration {
+    fn new(sse_algorithm: SseAlgorithm, kms_master_key_id: Option<&str>) -> Self {
+        Self {
+            rule: ServerSideEncryptionRule {
+                apply_server_side_encryption_by_default: ApplyServerSideEncryptionByDefault {
+                    sse_algorithm,
+                    kms_master_key_id: kms_master_key_id.map(|x| x.to_string()),
+                },
+            },
+        }
+    }
+
+    /// 使用SSE-COS加密
+    pub fn sse_cos() -> Self {
+        Self::new(SseAlgorithm::AES256, None)
+    }
+
+    /// 使用SSE-KMS加密，`kms_master_key_id`不传表示使用默认密钥
+    pub fn sse_kms(kms_master_key_id: Option<&str>) -> Self {
+        Self::new(SseAlgorithm::KMS, kms_master_key_id)
+    }
+
+    pub fn algorithm(&self) -> SseAlgorithm {
+        self.rule
+            .apply_server_side_encryption_by_default
+            .sse_algorithm
+    }
+}
+
+/// 静态网站的索引文档
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct IndexDocument {
+    /// 访问目录时返回的文档，如`index.html`
+    #[serde(rename = "Suffix")]
+    pub suffix: String,
+}
+
+/// 静态网站的错误文档
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct ErrorDocument {
+    /// 出错时返回的文档，如`error.html`
+    #[serde(rename = "Key")]
+    pub key: String,
+}
+
+/// 重定向所有请求
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct RedirectAllRequestsTo {
+    /// 重定向的协议，仅支持`https`
+    #[serde(rename = "Protocol")]
+    pub protocol: String,
+}
+
+/// 路由规则的条件，两者二选一
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Default)]
+pub struct RoutingCondition {
+    /// 返回指定的错误码时生效，仅支持4XX
+    #[serde(
+        rename = "HttpErrorCodeReturnedEquals",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub http_error_code_returned_equals: Option<u16>,
+    /// 对象键以指定前缀开头时生效
+    #[serde(
+        rename = "KeyPrefixEquals",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub key_prefix_equals: Option<String>,
+}
+
+/// 路由规则的重定向目标
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Default)]
+pub struct RoutingRedirect {
+    #[serde(rename = "Protocol", default, skip_serializing_if = "Option::is_none")]
+    pub protocol: Option<String>,
+    /// 替换整个对象键
+    #[serde(
+        rename = "ReplaceKeyWith",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub replace_key_with: Option<String>,
+    /// 替换条件中匹配的前缀
+    #[serde(
+        rename = "ReplaceKeyPrefixWith",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub replace_key_prefix_with: Option<String>,
+}
+
+/// 静态网站的路由规则
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct RoutingRule {
+    #[serde(rename = "Condition")]
+    pub condition: RoutingCondition,
+    #[serde(rename = "Redirect")]
+    pub redirect: RoutingRedirect,
+}
+
+impl RoutingRule {
+    /// 返回`code`错误码时重定向到`key`
+    pub fn on_error(code: u16, key: &str) -> Self {
+        Self {
+            condition: RoutingCondition {
+                http_error_code_returned_equals: Some(code),
+                ..Default::default()
+            },
+            redirect: RoutingRedirect {
+                replace_key_with: Some(key.to_string()),
+                ..Default::default()
+            },
+        }
+    }
+
+    /// 将前缀`prefix`替换为`replace_with`
+    pub fn on_prefix(prefix: &str, replace_with: &str) -> Self {
+        Self {
+            condition: RoutingCondition {
+                key_prefix_equals: Some(prefix.to_string()),
+                ..Default::default()
+            },
+            redirect: RoutingRedirect {
+                replace_key_prefix_with: Some(replace_with.to_string()),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Default)]
+pub struct RoutingRules {
+    #[serde(rename = "RoutingRule", default)]
+    pub routing_rule: Vec<RoutingRule>,
+}
+
+/// 静态网站配置
+/// <https://cloud.tencent.com/document/product/436/31930>
+/// ```
+/// use qcos::bucket::{RoutingRule, WebsiteConfiguration};
+/// use quick_xml::de::from_str;
+/// use quick_xml::se::to_string;
+/// let mut config = WebsiteConfiguration::new("index.html");
+/// config
+///     .error_document("404.html")
+///     .redirect_to_https()
+///     .routing_rule(RoutingRule::on_prefix("docs/v1/", "docs/v2/"));
+/// let s = to_string(&config).unwrap();
+/// assert_eq!(s, "<WebsiteConfiguration><IndexDocument><Suffix>index.html</Suffix></IndexDocument><RedirectAllRequestsTo><Protocol>https</Protocol></RedirectAllRequestsTo><ErrorDocument><Key>404.html</Key></ErrorDocument><RoutingRules><RoutingRule><Condition><KeyPrefixEquals>docs/v1/</KeyPrefixEquals></Condition><Redirect><ReplaceKeyPrefixWith>docs/v2/</ReplaceKeyPrefixWith></Redirect></RoutingRule></RoutingRules></WebsiteConfiguration>");
+/// assert_eq!(from_str::<WebsiteConfiguration>(&s).unwrap(), config);
+/// ```
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct WebsiteConfiguration {
+    #[serde(rename = "IndexDocument")]
+    pub index_document: IndexDocument,
+    #[serde(
+        rename = "RedirectAllRequestsTo",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub redirect_all_requests_to: Option<RedirectAllRequestsTo>,
+    #[serde(
+        rename = "ErrorDocument",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub error_document: Option<ErrorDocument>,
+    #[serde(
+        rename = "RoutingRules",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub routing_rules: Option<RoutingRules>,
+}
+
+impl WebsiteConfiguration {
+    pub fn new(index_suffix: &str) -> Self {
+        Self {
+            index_document: IndexDocument {
+                suffix: index_suffix.to_string(),
+            },
+            redirect_all_requests_to: None,
+            error_document: None,
+            routing_rules: None,
+        }
+    }
+
+    /// 设置错误文档
+    pub fn error_document(&mut self, key: &str) -> &mut Self {
+        self.error_document = Some(ErrorDocument {
+            key: key.to_string(),
+        });
+        self
+    }
+
+    /// 将所有http请求重定向到https
+    pub fn redirect_to_https(&mut self) -> &mut Self {
+        self.redirect_all_requests_to = Some(RedirectAllRequestsTo {
+            protocol: "https".to_string(),
+        });
+        self
+    }
+
+    /// 添加一条路由规则
+    pub fn routing_rule(&mut self, rule: RoutingRule) -> &mut Self {
+        self.routing_rules
+            .get_or_insert_with(Default::default)
+            .routing_rule
+            .push(rule);
+        self
+    }
+}
+
+/// 防盗链名单的类型
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Copy)]
+pub enum RefererType {
+    /// 白名单，只允许名单中的域名访问
+    #[serde(rename = "White-List")]
+    WhiteList,
+    /// 黑名单，禁止名单中的域名访问
+    #[serde(rename = "Black-List")]
+    BlackList,
+}
+
+/// 是否允许Referer为空的请求
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Copy)]
+pub enum EmptyReferer {
+    Allow,
+    Deny,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Default)]
+pub struct DomainList {
+    /// 域名，支持`*.example.com`形式的通配符
+    #[serde(rename = "Domain", default)]
+    pub domain: Vec<String>,
+}
+
+/// 防盗链配置
+/// <https://cloud.tencent.com/document/product/436/32492>
+/// ```
+/// use qcos::bucket::{EmptyReferer, RefererConfiguration, RefererType};
+/// use quick_xml::de::from_str;
+/// use quick_xml::se::to_string;
+/// let config = RefererConfiguration::new(RefererType::WhiteList, &["*.example.com"], EmptyReferer::Allow);
+/// let s = to_string(&config).unwrap();
+/// assert_eq!(s, "<RefererConfiguration><Status>Enabled</Status><RefererType>White-List</RefererType><DomainList><Domain>*.example.com</Domain></DomainList><EmptyReferConfiguration>Allow</EmptyReferConfiguration></RefererConfiguration>");
+/// assert_eq!(from_str::<RefererConfiguration>(&s).unwrap(), config);
+/// ```
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct RefererConfiguration {
+    #[serde(rename = "Status")]
+    pub status: RuleStatus,
+    #[serde(rename = "RefererType")]
+    pub referer_type: RefererType,
+    #[serde(rename = "DomainList", default)]
+    pub domain_list: DomainList,
+    #[serde(
+        rename = "EmptyReferConfiguration",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub empty_refer_configuration: Option<EmptyReferer>,
+}
+
+impl RefererConfiguration {
+    /// 创建启用状态的防盗链配置
+    pub fn new(referer_type: RefererType, domains: &[&str], empty_referer: EmptyReferer) -> Self {
+        Self {
+            status: RuleStatus::Enabled,
+            referer_type,
+            domain_list: DomainList {
+                domain: domains.iter().map(|x| x.to_string()).collect(),
+            },
+            empty_refer_configuration: Some(empty_referer),
+        }
+    }
+}
+
+/// 访问日志的投递位置
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct LoggingEnabled {
+    /// 存放日志的存储桶，需与源存储桶在同一地域
+    #[serde(rename = "TargetBucket")]
+    pub target_bucket: String,
+    /// 日志文件的前缀
+    #[serde(rename = "TargetPrefix", default)]
+    pub target_prefix: String,
+}
+
+/// 存储桶的访问日志配置
+/// <https://cloud.tencent.com/document/product/436/17054>
+/// ```
+/// use qcos::bucket::BucketLoggingStatus;
+/// use quick_xml::de::from_str;
+/// use quick_xml::se::to_string;
+/// let status = BucketLoggingStatus::enabled("logbucket-1250000000", "logs/");
+/// let s = to_string(&status).unwrap();
+/// assert_eq!(s, "<BucketLoggingStatus><LoggingEnabled><TargetBucket>logbucket-1250000000</TargetBucket><TargetPrefix>logs/</TargetPrefix></LoggingEnabled></BucketLoggingStatus>");
+/// assert_eq!(from_str::<BucketLoggingStatus>(&s).unwrap(), status);
+/// assert_eq!(to_string(&BucketLoggingStatus::disabled()).unwrap(), "<BucketLoggingStatus/>");
+/// ```
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Default)]
+pub struct BucketLoggingStatus {
+    /// 未开启时为`None`
+    #[serde(
+        rename = "LoggingEnabled",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub logging_enabled: Option<LoggingEnabled>,
+}
+
+impl BucketLoggingStatus {
+    /// 开启访问日志，投递到`target_bucket`中前缀为`target_prefix`的位置
+    pub fn enabled(target_bucket: &str, target_prefix: &str) -> Self {
+        Self {
+            logging_enabled: Some(LoggingEnabled {
+                target_bucket: target_bucket.to_string(),
+                target_prefix: target_prefix.to_string(),
+            }),
+        }
+    }
+
+    /// 关闭访问日志
+    pub fn disabled() -> Self {
+        Self::default()
+    }
+}
+
+/// 创建存储桶时的请求体
+/// <https://cloud.tencent.com/document/product/436/7738>
+/// ```
+/// use qcos::bucket::CreateBucketConfiguration;
+/// use quick_xml::se::to_string;
+/// let s = to_string(&CreateBucketConfiguration::maz()).unwrap();
+/// assert_eq!(s, "<CreateBucketConfiguration><BucketAZConfig>MAZ</BucketAZConfig></CreateBucketConfiguration>");
+/// ```
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Default)]
+pub struct CreateBucketConfiguration {
+    /// 为`MAZ`时创建多AZ存储桶
+    #[serde(
+        rename = "BucketAZConfig",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub bucket_az_config: Option<String>,
+}
+
+impl CreateBucketConfiguration {
+    /// 多AZ存储桶
+    pub fn maz() -> Self {
+        Self {
+            bucket_az_config: Some("MAZ".to_string()),
+        }
+    }
+}
+
+/// 存储桶的状态，见[`Client::bucket_status`]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum BucketStatus {
+    /// 存储桶存在且有读取权限
+    Exists,
+    /// 存储桶不存在
+    NotFound,
+    /// 存储桶存在但没有访问权限，也可能属于其他账号
+    Forbidden,
+}
+
+/// [`Client::delete_bucket_force`]的执行结果，`dry_run`时为将要删除的内容
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct ForceDeleteReport {
+    /// 对象及其历史版本，未开启版本控制时版本ID为`null`
+    pub versions: Vec<ObjectIdentifier>,
+    /// 删除标记
+    pub delete_markers: Vec<ObjectIdentifier>,
+    /// 进行中的分块上传
+    pub uploads: Vec<Upload>,
+    /// 存储桶是否已删除，`dry_run`时为`false`
+    pub bucket_deleted: bool,
+}
+
+/// 子资源的查询参数，如`?cors`
+pub(crate) fn resource_query(resource: &str) -> HashMap<String, String> {
+    let mut query = HashMap::new();
+    query.insert(resource.to_string(), String::new());
+    query
+}
+
 impl Client {
     /// 创建一个存储桶
     /// <https://cloud.tencent.com/document/product/436/7738>
@@ -27,18 +574,89 @@ impl Client {
     /// };
     /// ```
     pub async fn put_bucket(&self, acl_header: Option<AclHeader>) -> Response {
-        let headers = self.get_headers_with_auth("put", "/", acl_header, None, None);
+        self.put_bucket_with_options(acl_header, &BucketOptions::new())
+            .await
+    }
+
+    /// 创建一个存储桶，可指定多AZ及初始标签
+    /// <https://cloud.tencent.com/document/product/436/7738>
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// use qcos::options::BucketOptions;
+    /// use qcos::tagging::Tagging;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let mut tagging = Tagging::new();
+    /// tagging.add_tag("env", "test");
+    /// let mut options = BucketOptions::new();
+    /// options.insert_maz().insert_tagging(&tagging).unwrap();
+    /// let res = client.put_bucket_with_options(None, &options).await;
+    /// assert_eq!(res.status_code(), Some(403));
+    /// };
+    /// ```
+    pub async fn put_bucket_with_options(
+        &self,
+        acl_header: Option<AclHeader>,
+        options: &BucketOptions,
+    ) -> Response {
+        let mut headers = self.get_common_headers();
+        options.extend_headers(&mut headers);
+        let mut body = None;
+        if let Some(config) = options.get_configuration() {
+            let s = match to_string(config) {
+                Ok(s) => s,
+                Err(e) => return Response::new(ErrNo::ENCODE, e.to_string(), Default::default()),
+            };
+            headers.insert(
+                CONTENT_TYPE,
+                HeaderValue::from_str("application/xml").unwrap(),
+            );
+            headers.insert(
+                HeaderName::from_static("content-md5"),
+                HeaderValue::from_str(&content_md5(s.as_bytes())).unwrap(),
+            );
+            body = Some(Body::from(s));
+        }
+        let headers = self.get_headers_with_auth("put", "/", acl_header, Some(headers), None);
         let resp = Request::put(
             self.get_full_url_from_path("/").as_str(),
             None,
             Some(&headers),
             None,
             None,
-            None as Option<Body>,
+            body,
         )
         .await;
         self.make_response(resp)
     }
+
+    /// 创建存储桶，存储桶已存在且属于当前账号(`BucketAlreadyOwnedByYou`)时也返回`SUCCESS`
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// use qcos::options::BucketOptions;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let res = client.ensure_bucket(None, &BucketOptions::new()).await;
+    /// assert_eq!(res.status_code(), Some(403));
+    /// };
+    /// ```
+    pub async fn ensure_bucket(
+        &self,
+        acl_header: Option<AclHeader>,
+        options: &BucketOptions,
+    ) -> Response {
+        let resp = self.put_bucket_with_options(acl_header, options).await;
+        match resp.error_response() {
+            Some(e) if e.code == "BucketAlreadyOwnedByYou" => Response {
+                error_no: ErrNo::SUCCESS,
+                error_message: String::new(),
+                ..resp
+            },
+            _ => resp,
+        }
+    }
     /// 删除指定的存储桶。该 API 的请求者需要对存储桶有写入权限。
     /// <https://cloud.tencent.com/document/product/436/7732>
     /// # Examples
@@ -62,6 +680,104 @@ impl Client {
         .await;
         self.make_response(resp)
     }
+    /// 清空并删除存储桶，依次删除所有对象、历史版本及删除标记，取消进行中的分块上传，最后删除存储桶
+    ///
+    /// 操作不可恢复，`confirm`需与存储桶名称一致，否则返回`ErrNo::PARAM`。
+    /// `dry_run`为`true`时只列出将要删除的内容，不做任何修改。
+    /// 中途失败时已删除的内容无法恢复，可以再次调用继续删除。
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let report = client.delete_bucket_force("qcloudtest-xxx", true).await;
+    /// if let Ok(report) = report {
+    ///     println!("{} versions, {} delete markers, {} uploads", report.versions.len(), report.delete_markers.len(), report.uploads.len());
+    /// }
+    /// };
+    /// ```
+    pub async fn delete_bucket_force(
+        &self,
+        confirm: &str,
+        dry_run: bool,
+    ) -> Result<ForceDeleteReport, Response> {
+        if confirm != self.get_bucket() {
+            return Err(Response::new(
+                ErrNo::PARAM,
+                format!("确认参数{}与存储桶名称{}不一致", confirm, self.get_bucket()),
+                Default::default(),
+            ));
+        }
+        let mut report = ForceDeleteReport::default();
+        let mut key_marker = String::new();
+        let mut version_id_marker = String::new();
+        loop {
+            let result = self
+                .list_object_versions("", &key_marker, &version_id_marker, 1000)
+                .await?;
+            let versions: Vec<ObjectIdentifier> = result
+                .version
+                .iter()
+                .map(|x| ObjectIdentifier::new(&x.key, &x.version_id))
+                .collect();
+            let delete_markers: Vec<ObjectIdentifier> = result
+                .delete_marker
+                .iter()
+                .map(|x| ObjectIdentifier::new(&x.key, &x.version_id))
+                .collect();
+            if !dry_run {
+                let objects: Vec<ObjectIdentifier> =
+                    versions.iter().chain(&delete_markers).cloned().collect();
+                for chunk in objects.chunks(crate::objects::MAX_DELETE_OBJECTS) {
+                    let res = self.delete_objects(chunk, true).await?;
+                    if let Some(e) = res.error.first() {
+                        return Err(Response::new(
+                            ErrNo::OTHER,
+                            format!("删除对象{}失败: {} {}", e.key, e.code, e.message),
+                            Default::default(),
+                        ));
+                    }
+                }
+            }
+            report.versions.extend(versions);
+            report.delete_markers.extend(delete_markers);
+            if !result.is_truncated {
+                break;
+            }
+            key_marker = result.next_key_marker;
+            version_id_marker = result.next_version_id_marker;
+        }
+        let mut key_marker = String::new();
+        let mut upload_id_marker = String::new();
+        loop {
+            let result = self
+                .list_multipart_uploads("", &key_marker, &upload_id_marker, 1000)
+                .await?;
+            for upload in result.upload {
+                if !dry_run {
+                    let resp = self.abort_object_part(&upload.key, &upload.upload_id).await;
+                    if resp.error_no != ErrNo::SUCCESS {
+                        return Err(resp);
+                    }
+                }
+                report.uploads.push(upload);
+            }
+            if !result.is_truncated {
+                break;
+            }
+            key_marker = result.next_key_marker;
+            upload_id_marker = result.next_upload_id_marker;
+        }
+        if !dry_run {
+            let resp = self.delete_bucket().await;
+            if resp.error_no != ErrNo::SUCCESS {
+                return Err(resp);
+            }
+            report.bucket_deleted = true;
+        }
+        Ok(report)
+    }
+
     /// 列出该存储桶内的部分或者全部对象。该 API 的请求者需要对存储桶有读取权限。
     /// <https://cloud.tencent.com/document/product/436/7734>
     /// # Examples
@@ -112,6 +828,7 @@ impl Client {
     /// 存储桶存在且有读取权限，返回 `SUCCESS`
     /// 无存储桶读取权限，返回 `ErrNo::STATUS`, error_message包含403。
     /// 存储桶不存在，返回 `ErrNo::STATUS`, error_message包含404。
+    /// 需要区分这几种情况时使用[`bucket_status`](Self::bucket_status)
     /// # Examples
     /// ```
     /// use qcos::client::Client;
@@ -131,6 +848,34 @@ impl Client {
         .await;
         self.make_response(resp)
     }
+
+    /// 查询存储桶是否存在及是否有权限访问，其他错误(如网络错误)返回`Err`
+    /// <https://cloud.tencent.com/document/product/436/7735>
+    /// # Examples
+    /// ```
+    /// use qcos::bucket::BucketStatus;
+    /// use qcos::client::Client;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// match client.bucket_status().await {
+    ///     Ok(BucketStatus::Exists) => println!("exists"),
+    ///     Ok(BucketStatus::NotFound) => println!("not found"),
+    ///     Ok(BucketStatus::Forbidden) => println!("forbidden"),
+    ///     Err(e) => println!("{}", e),
+    /// }
+    /// };
+    /// ```
+    pub async fn bucket_status(&self) -> Result<BucketStatus, Response> {
+        let resp = self.check_bucket().await;
+        if resp.error_no == ErrNo::SUCCESS {
+            return Ok(BucketStatus::Exists);
+        }
+        match resp.status_code() {
+            Some(404) => Ok(BucketStatus::NotFound),
+            Some(403) => Ok(BucketStatus::Forbidden),
+            _ => Err(resp),
+        }
+    }
     /// 写入存储桶的访问控制列表
     /// <https://cloud.tencent.com/document/product/436/7737>
     /// # Examples
@@ -161,4 +906,547 @@ impl Client {
         .await;
         self.make_response(resp)
     }
+
+    /// 通过请求体写入存储桶的访问控制列表（ACL），会覆盖已有的ACL
+    /// <https://cloud.tencent.com/document/product/436/7737>
+    /// # Examples
+    /// ```
+    /// use qcos::acl::{Grantee, Permission};
+    /// use qcos::client::Client;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// if let Ok(mut policy) = client.get_bucket_acl().await {
+    ///     policy.grant(Grantee::user("100000000002"), Permission::WRITE);
+    ///     let res = client.put_bucket_acl_policy(&policy).await;
+    /// }
+    /// };
+    /// ```
+    pub async fn put_bucket_acl_policy(&self, policy: &AccessControlPolicy) -> Response {
+        self.put_bucket_config("acl", policy).await
+    }
+
+    /// 查询存储桶的访问控制列表（ACL）
+    /// <https://cloud.tencent.com/document/product/436/7733>
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let res = client.get_bucket_acl().await;
+    /// assert!(res.is_err());
+    /// };
+    /// ```
+    pub async fn get_bucket_acl(&self) -> Result<AccessControlPolicy, Response> {
+        self.get_bucket_config("acl").await
+    }
+
+    /// 写入存储桶的子资源配置，如`versioning`，配置序列化为xml作为请求体
+    pub(crate) async fn put_bucket_config<T: serde::Serialize>(
+        &self,
+        resource: &str,
+        config: &T,
+    ) -> Response {
+        self.put_bucket_config_with_query(resource_query(resource), config)
+            .await
+    }
+
+    /// 同[`put_bucket_config`](Self::put_bucket_config)，`query`中除子资源外还可以带其他参数，如清单的`id`
+    pub(crate) async fn put_bucket_config_with_query<T: serde::Serialize>(
+        &self,
+        query: HashMap<String, String>,
+        config: &T,
+    ) -> Response {
+        let body = match to_string(config) {
+            Ok(s) => s,
+            Err(e) => return Response::new(ErrNo::ENCODE, e.to_string(), Default::default()),
+        };
+        let mut headers = self.get_common_headers();
+        headers.insert(
+            CONTENT_TYPE,
+            HeaderValue::from_str("application/xml").unwrap(),
+        );
+        // 部分配置接口要求携带Content-MD5
+        headers.insert(
+            HeaderName::from_static("content-md5"),
+            HeaderValue::from_str(&content_md5(body.as_bytes())).unwrap(),
+        );
+        let headers =
+            self.get_headers_with_auth("put", "/", None, Some(headers), Some(query.clone()));
+        let resp = Request::put(
+            self.get_full_url_from_path("/").as_str(),
+            Some(&query),
+            Some(&headers),
+            None,
+            None,
+            Some(body),
+        )
+        .await;
+        self.make_response(resp)
+    }
+
+    /// 查询存储桶的子资源配置，如`versioning`，返回的xml解析为`T`
+    pub(crate) async fn get_bucket_config<T: serde::de::DeserializeOwned>(
+        &self,
+        resource: &str,
+    ) -> Result<T, Response> {
+        self.get_bucket_config_with_query(resource_query(resource))
+            .await
+    }
+
+    /// 同[`get_bucket_config`](Self::get_bucket_config)，`query`中除子资源外还可以带其他参数
+    pub(crate) async fn get_bucket_config_with_query<T: serde::de::DeserializeOwned>(
+        &self,
+        query: HashMap<String, String>,
+    ) -> Result<T, Response> {
+        let headers = self.get_headers_with_auth("get", "/", None, None, Some(query.clone()));
+        let resp = Request::get(
+            self.get_full_url_from_path("/").as_str(),
+            Some(&query),
+            Some(&headers),
+        )
+        .await;
+        self.make_xml_response(resp)
+    }
+
+    /// 删除存储桶的子资源配置，如`cors`
+    pub(crate) async fn delete_bucket_config(&self, resource: &str) -> Response {
+        self.delete_bucket_config_with_query(resource_query(resource))
+            .await
+    }
+
+    /// 同[`delete_bucket_config`](Self::delete_bucket_config)，`query`中除子资源外还可以带其他参数
+    pub(crate) async fn delete_bucket_config_with_query(
+        &self,
+        query: HashMap<String, String>,
+    ) -> Response {
+        let headers = self.get_headers_with_auth("delete", "/", None, None, Some(query.clone()));
+        let resp = Request::delete(
+            self.get_full_url_from_path("/").as_str(),
+            Some(&query),
+            Some(&headers),
+            None,
+            None,
+        )
+        .await;
+        self.make_response(resp)
+    }
+
+    /// 设置存储桶的跨域访问规则，会覆盖已有的规则
+    /// <https://cloud.tencent.com/document/product/436/8279>
+    /// # Examples
+    /// ```
+    /// use qcos::bucket::{CORSConfiguration, CORSRule, CorsMethod};
+    /// use qcos::client::Client;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let mut rule = CORSRule::new(&["https://www.example.com"], &[CorsMethod::PUT, CorsMethod::POST]);
+    /// rule.allowed_headers.push("*".to_string());
+    /// rule.expose_headers.push("ETag".to_string());
+    /// rule.max_age_seconds = Some(600);
+    /// let res = client.put_bucket_cors(&CORSConfiguration { rules: vec![rule] }).await;
+    /// assert!(res.error_message.contains("403"));
+    /// };
+    /// ```
+    pub async fn put_bucket_cors(&self, config: &CORSConfiguration) -> Response {
+        self.put_bucket_config("cors", config).await
+    }
+
+    /// 查询存储桶的跨域访问规则，未设置时返回404
+    /// <https://cloud.tencent.com/document/product/436/8274>
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// if let Ok(config) = client.get_bucket_cors().await {
+    ///     println!("{:?}", config.rules);
+    /// }
+    /// };
+    /// ```
+    pub async fn get_bucket_cors(&self) -> Result<CORSConfiguration, Response> {
+        self.get_bucket_config("cors").await
+    }
+
+    /// 删除存储桶的跨域访问规则
+    /// <https://cloud.tencent.com/document/product/436/8283>
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let res = client.delete_bucket_cors().await;
+    /// assert!(res.error_message.contains("403"));
+    /// };
+    /// ```
+    pub async fn delete_bucket_cors(&self) -> Response {
+        self.delete_bucket_config("cors").await
+    }
+
+    /// 模拟浏览器发送跨域预检请求，用于验证跨域访问规则，预检请求不需要签名
+    /// <https://cloud.tencent.com/document/product/436/8288>
+    /// 规则匹配时返回成功，响应头中包含`access-control-allow-origin`、`access-control-allow-methods`等；
+    /// 不匹配时返回403
+    /// # 参数
+    /// - key: 对象的key
+    /// - origin: 请求来源，如`https://www.example.com`
+    /// - method: 实际请求将使用的方法
+    /// - request_headers: 实际请求将携带的请求头
+    ///
+    /// # Examples
+    /// ```
+    /// use qcos::bucket::CorsMethod;
+    /// use qcos::client::Client;
+    /// use qcos::request::ErrNo;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let res = client.options_object("Cargo.toml", "https://www.example.com", CorsMethod::PUT, &["content-type"]).await;
+    /// if res.error_no == ErrNo::SUCCESS {
+    ///     assert_eq!(res.headers["access-control-allow-origin"], "https://www.example.com");
+    /// }
+    /// };
+    /// ```
+    pub async fn options_object(
+        &self,
+        key: &str,
+        origin: &str,
+        method: CorsMethod,
+        request_headers: &[&str],
+    ) -> Response {
+        let url_path = self.get_path_from_object_key(key);
+        let mut headers = self.get_common_headers();
+        headers.insert(
+            HeaderName::from_static("origin"),
+            match HeaderValue::from_str(origin) {
+                Ok(v) => v,
+                Err(e) => return Response::new(ErrNo::PARAM, e.to_string(), Default::default()),
+            },
+        );
+        headers.insert(
+            HeaderName::from_static("access-control-request-method"),
+            HeaderValue::from_str(&method.to_string()).unwrap(),
+        );
+        if !request_headers.is_empty() {
+            headers.insert(
+                HeaderName::from_static("access-control-request-headers"),
+                match HeaderValue::from_str(&request_headers.join(",")) {
+                    Ok(v) => v,
+                    Err(e) => {
+                        return Response::new(ErrNo::PARAM, e.to_string(), Default::default())
+                    }
+                },
+            );
+        }
+        let resp = Request::options(
+            self.get_full_url_from_path(url_path.as_str()).as_str(),
+            None,
+            Some(&headers),
+        )
+        .await;
+        self.make_response(resp)
+    }
+
+    /// 设置存储桶的默认加密方式
+    /// <https://cloud.tencent.com/document/product/436/40136>
+    /// # Examples
+    /// ```
+    /// use qcos::bucket::ServerSideEncryptionConfiguration;
+    /// use qcos::client::Client;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let res = client.put_bucket_encryption(&ServerSideEncryptionConfiguration::sse_cos()).await;
+    /// assert!(res.error_message.contains("403"));
+    /// };
+    /// ```
+    pub async fn put_bucket_encryption(
+        &self,
+        config: &ServerSideEncryptionConfiguration,
+    ) -> Response {
+        self.put_bucket_config("encryption", config).await
+    }
+
+    /// 查询存储桶的默认加密方式，未设置时返回404
+    /// <https://cloud.tencent.com/document/product/436/40137>
+    /// # Examples
+    /// ```
+    /// use qcos::bucket::SseAlgorithm;
+    /// use qcos::client::Client;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// if let Ok(config) = client.get_bucket_encryption().await {
+    ///     assert_eq!(config.algorithm(), SseAlgorithm::AES256);
+    /// }
+    /// };
+    /// ```
+    pub async fn get_bucket_encryption(
+        &self,
+    ) -> Result<ServerSideEncryptionConfiguration, Response> {
+        self.get_bucket_config("encryption").await
+    }
+
+    /// 删除存储桶的默认加密方式
+    /// <https://cloud.tencent.com/document/product/436/40138>
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let res = client.delete_bucket_encryption().await;
+    /// assert!(res.error_message.contains("403"));
+    /// };
+    /// ```
+    pub async fn delete_bucket_encryption(&self) -> Response {
+        self.delete_bucket_config("encryption").await
+    }
+
+    /// 设置存储桶的静态网站配置
+    /// <https://cloud.tencent.com/document/product/436/31930>
+    /// # Examples
+    /// ```
+    /// use qcos::bucket::{RoutingRule, WebsiteConfiguration};
+    /// use qcos::client::Client;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let mut config = WebsiteConfiguration::new("index.html");
+    /// config.error_document("404.html").routing_rule(RoutingRule::on_error(404, "404.html"));
+    /// let res = client.put_bucket_website(&config).await;
+    /// assert!(res.error_message.contains("403"));
+    /// };
+    /// ```
+    pub async fn put_bucket_website(&self, config: &WebsiteConfiguration) -> Response {
+        self.put_bucket_config("website", config).await
+    }
+
+    /// 查询存储桶的静态网站配置，未设置时返回404
+    /// <https://cloud.tencent.com/document/product/436/31929>
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// if let Ok(config) = client.get_bucket_website().await {
+    ///     println!("{}", config.index_document.suffix);
+    /// }
+    /// };
+    /// ```
+    pub async fn get_bucket_website(&self) -> Result<WebsiteConfiguration, Response> {
+        self.get_bucket_config("website").await
+    }
+
+    /// 删除存储桶的静态网站配置
+    /// <https://cloud.tencent.com/document/product/436/31928>
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let res = client.delete_bucket_website().await;
+    /// assert!(res.error_message.contains("403"));
+    /// };
+    /// ```
+    pub async fn delete_bucket_website(&self) -> Response {
+        self.delete_bucket_config("website").await
+    }
+
+    /// 设置存储桶的防盗链配置
+    /// <https://cloud.tencent.com/document/product/436/32492>
+    /// # Examples
+    /// ```
+    /// use qcos::bucket::{EmptyReferer, RefererConfiguration, RefererType};
+    /// use qcos::client::Client;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let config = RefererConfiguration::new(RefererType::WhiteList, &["*.example.com"], EmptyReferer::Deny);
+    /// let res = client.put_bucket_referer(&config).await;
+    /// assert!(res.error_message.contains("403"));
+    /// };
+    /// ```
+    pub async fn put_bucket_referer(&self, config: &RefererConfiguration) -> Response {
+        self.put_bucket_config("referer", config).await
+    }
+
+    /// 查询存储桶的防盗链配置
+    /// <https://cloud.tencent.com/document/product/436/32493>
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// if let Ok(config) = client.get_bucket_referer().await {
+    ///     println!("{:?}", config.domain_list.domain);
+    /// }
+    /// };
+    /// ```
+    pub async fn get_bucket_referer(&self) -> Result<RefererConfiguration, Response> {
+        self.get_bucket_config("referer").await
+    }
+
+    /// 关闭存储桶的防盗链，COS没有删除防盗链配置的接口，通过写入关闭状态的配置实现
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let res = client.delete_bucket_referer().await;
+    /// assert!(res.error_message.contains("403"));
+    /// };
+    /// ```
+    pub async fn delete_bucket_referer(&self) -> Response {
+        let mut config =
+            RefererConfiguration::new(RefererType::WhiteList, &[], EmptyReferer::Allow);
+        config.status = RuleStatus::Disabled;
+        self.put_bucket_config("referer", &config).await
+    }
+
+    /// 开启或关闭存储桶的访问日志，关闭时传入[`BucketLoggingStatus::disabled`]
+    /// <https://cloud.tencent.com/document/product/436/17054>
+    /// # Examples
+    /// ```
+    /// use qcos::bucket::BucketLoggingStatus;
+    /// use qcos::client::Client;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let status = BucketLoggingStatus::enabled("logbucket-1250000000", "qcloudtest-xxx/");
+    /// let res = client.put_bucket_logging(&status).await;
+    /// assert!(res.error_message.contains("403"));
+    /// };
+    /// ```
+    pub async fn put_bucket_logging(&self, status: &BucketLoggingStatus) -> Response {
+        self.put_bucket_config("logging", status).await
+    }
+
+    /// 查询存储桶的访问日志配置
+    /// <https://cloud.tencent.com/document/product/436/17053>
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// if let Ok(status) = client.get_bucket_logging().await {
+    ///     println!("{:?}", status.logging_enabled);
+    /// }
+    /// };
+    /// ```
+    pub async fn get_bucket_logging(&self) -> Result<BucketLoggingStatus, Response> {
+        self.get_bucket_config("logging").await
+    }
+
+    /// 开启或暂停存储桶的版本控制，开启后无法关闭，只能暂停
+    /// <https://cloud.tencent.com/document/product/436/19889>
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let res = client.put_bucket_versioning(true).await;
+    /// assert!(res.error_message.contains("403"));
+    /// };
+    /// ```
+    pub async fn put_bucket_versioning(&self, enabled: bool) -> Response {
+        let status = if enabled {
+            VersioningStatus::Enabled
+        } else {
+            VersioningStatus::Suspended
+        };
+        let config = VersioningConfiguration {
+            status: Some(status),
+        };
+        self.put_bucket_config("versioning", &config).await
+    }
+
+    /// 查询存储桶的版本控制状态
+    /// <https://cloud.tencent.com/document/product/436/19888>
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// use qcos::request::VersioningStatus;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// if let Ok(config) = client.get_bucket_versioning().await {
+    ///     let enabled = config.status == Some(VersioningStatus::Enabled);
+    /// }
+    /// };
+    /// ```
+    pub async fn get_bucket_versioning(&self) -> Result<VersioningConfiguration, Response> {
+        self.get_bucket_config("versioning").await
+    }
+
+    /// 查询存储桶中对象的历史版本及删除标记，单次最多返回1000条
+    /// <https://cloud.tencent.com/document/product/436/35521>
+    /// # 参数
+    /// - prefix: 对象key的前缀
+    /// - key_marker: 从该key之后开始列出，首次查询为空，之后使用结果中的`next_key_marker`
+    /// - version_id_marker: 配合`key_marker`使用，之后使用结果中的`next_version_id_marker`
+    /// - max_keys: 最大返回条数，1-1000之间，其他值使用默认值1000
+    ///
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let mut key_marker = String::new();
+    /// let mut version_id_marker = String::new();
+    /// loop {
+    ///     let res = client.list_object_versions("test/", &key_marker, &version_id_marker, 1000).await.unwrap();
+    ///     for version in res.version {
+    ///         println!("{} {} {}", version.key, version.version_id, version.is_latest);
+    ///     }
+    ///     if !res.is_truncated {
+    ///         break;
+    ///     }
+    ///     key_marker = res.next_key_marker;
+    ///     version_id_marker = res.next_version_id_marker;
+    /// }
+    /// };
+    /// ```
+    pub async fn list_object_versions(
+        &self,
+        prefix: &str,
+        key_marker: &str,
+        version_id_marker: &str,
+        max_keys: i32,
+    ) -> Result<ListVersionsResult, Response> {
+        let mut query = HashMap::new();
+        query.insert("versions".to_string(), String::new());
+        if !prefix.is_empty() {
+            query.insert("prefix".to_string(), prefix.to_string());
+        }
+        if !key_marker.is_empty() {
+            query.insert("key-marker".to_string(), key_marker.to_string());
+        }
+        if !version_id_marker.is_empty() {
+            query.insert(
+                "version-id-marker".to_string(),
+                version_id_marker.to_string(),
+            );
+        }
+        if max_keys <= 1000 && max_keys > 0 {
+            query.insert("max-keys".to_string(), max_keys.to_string());
+        }
+        let headers = self.get_headers_with_auth("get", "/", None, None, Some(query.clone()));
+        let resp = Request::get(
+            self.get_full_url_from_path("/").as_str(),
+            Some(&query),
+            Some(&headers),
+        )
+        .await;
+        self.make_xml_response(resp)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::client::Client;
+    use crate::request::ErrNo;
+
+    #[tokio::test]
+    async fn test_delete_bucket_force_confirm() {
+        // 确认参数不一致时不发出任何请求
+        let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+        let err = client
+            .delete_bucket_force("qcloudtest-yyy", false)
+            .await
+            .unwrap_err();
+        assert_eq!(err.error_no, ErrNo::PARAM);
+        assert!(err.error_message.contains("qcloudtest-xxx"));
+    }
 }
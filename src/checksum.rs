@@ -0,0 +1,230 @@
+//! 数据完整性校验
+//!
+//! COS 使用 CRC64-ECMA 计算对象的校验值，并在响应头`x-cos-hash-crc64ecma`中返回
+//! <https://cloud.tencent.com/document/product/436/40334>
+use base64::Engine;
+use md5::{Digest, Md5};
+use std::collections::HashMap;
+use tokio::io::{self, AsyncRead, AsyncReadExt};
+
+use crate::request::{ErrNo, Response};
+
+/// 对象CRC64校验值的响应头
+pub const CRC64_HEADER: &str = "x-cos-hash-crc64ecma";
+
+// ECMA-182 多项式(反转)
+const POLY: u64 = 0xC96C_5795_D787_0F42;
+
+const fn make_table() -> [u64; 256] {
+    let mut table = [0u64; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u64;
+        let mut j = 0;
+        while j < 8 {
+            if crc & 1 == 1 {
+                crc = (crc >> 1) ^ POLY;
+            } else {
+                crc >>= 1;
+            }
+            j += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
+
+static TABLE: [u64; 256] = make_table();
+
+/// 增量计算CRC64-ECMA
+/// # Examples
+/// ```
+/// use qcos::checksum::Crc64;
+/// let mut crc = Crc64::new();
+/// crc.update(b"12345");
+/// crc.update(b"6789");
+/// assert_eq!(crc.value(), 0x995DC9BBDF1939FA);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Crc64 {
+    value: u64,
+}
+
+impl Crc64 {
+    pub fn new() -> Self {
+        Self { value: 0 }
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        let mut crc = !self.value;
+        for b in data {
+            crc = TABLE[((crc ^ *b as u64) & 0xff) as usize] ^ (crc >> 8);
+        }
+        self.value = !crc;
+    }
+
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+}
+
+/// 计算数据的CRC64-ECMA
+pub fn crc64(data: &[u8]) -> u64 {
+    let mut crc = Crc64::new();
+    crc.update(data);
+    crc.value()
+}
+
+fn gf2_matrix_times(mat: &[u64; 64], mut vec: u64) -> u64 {
+    let mut sum = 0;
+    let mut i = 0;
+    while vec != 0 {
+        if vec & 1 == 1 {
+            sum ^= mat[i];
+        }
+        vec >>= 1;
+        i += 1;
+    }
+    sum
+}
+
+fn gf2_matrix_square(square: &mut [u64; 64], mat: &[u64; 64]) {
+    for n in 0..64 {
+        square[n] = gf2_matrix_times(mat, mat[n]);
+    }
+}
+
+/// 合并两段数据的CRC64，`crc2`对应的数据长度为`len2`，用于由分片的CRC64计算整个对象的CRC64
+/// # Examples
+/// ```
+/// use qcos::checksum::{crc64, crc64_combine};
+/// let crc = crc64_combine(crc64(b"12345"), crc64(b"6789"), 4);
+/// assert_eq!(crc, crc64(b"123456789"));
+/// ```
+pub fn crc64_combine(mut crc1: u64, crc2: u64, mut len2: u64) -> u64 {
+    if len2 == 0 {
+        return crc1;
+    }
+    let mut even = [0u64; 64];
+    let mut odd = [0u64; 64];
+    // 1个0比特对应的矩阵
+    odd[0] = POLY;
+    let mut row = 1;
+    for item in odd.iter_mut().skip(1) {
+        *item = row;
+        row <<= 1;
+    }
+    // 2个0比特
+    gf2_matrix_square(&mut even, &odd);
+    // 4个0比特
+    gf2_matrix_square(&mut odd, &even);
+    loop {
+        gf2_matrix_square(&mut even, &odd);
+        if len2 & 1 == 1 {
+            crc1 = gf2_matrix_times(&even, crc1);
+        }
+        len2 >>= 1;
+        if len2 == 0 {
+            break;
+        }
+        gf2_matrix_square(&mut odd, &even);
+        if len2 & 1 == 1 {
+            crc1 = gf2_matrix_times(&odd, crc1);
+        }
+        len2 >>= 1;
+        if len2 == 0 {
+            break;
+        }
+    }
+    crc1 ^ crc2
+}
+
+/// 计算`Content-MD5`请求头的值，即MD5摘要的base64编码
+/// # Examples
+/// ```
+/// use qcos::checksum::content_md5;
+/// assert_eq!(content_md5(b"ccccccccccccc"), "jXTFNMFaS6g8cRAKEDdAdQ==");
+/// ```
+pub fn content_md5(data: &[u8]) -> String {
+    encode_md5(Md5::digest(data).as_slice())
+}
+
+/// 将MD5摘要编码为`Content-MD5`请求头的值
+pub fn encode_md5(digest: &[u8]) -> String {
+    base64::engine::general_purpose::STANDARD.encode(digest)
+}
+
+/// 流式读取`reader`的全部数据，返回`Content-MD5`请求头的值及CRC64
+pub async fn checksum_reader<R: AsyncRead + Unpin>(mut reader: R) -> io::Result<(String, u64)> {
+    let mut md5 = Md5::new();
+    let mut crc = Crc64::new();
+    let mut buf = vec![0u8; 64 * 1024];
+    loop {
+        let n = reader.read(&mut buf).await?;
+        if n == 0 {
+            break;
+        }
+        md5.update(&buf[..n]);
+        crc.update(&buf[..n]);
+    }
+    Ok((encode_md5(md5.finalize().as_slice()), crc.value()))
+}
+
+/// 将本地计算的CRC64与响应头`x-cos-hash-crc64ecma`比较，响应头不存在时不校验
+pub fn verify_crc64(headers: &HashMap<String, String>, crc: u64) -> Result<(), Response> {
+    let expected = match headers.get(CRC64_HEADER) {
+        Some(v) => v,
+        None => return Ok(()),
+    };
+    if expected.parse::<u64>().ok() != Some(crc) {
+        return Err(Response::new(
+            ErrNo::INTEGRITY,
+            format!("CRC64校验失败, 服务端: {}, 本地: {}", expected, crc),
+            Default::default(),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::checksum::{
+        checksum_reader, content_md5, crc64, crc64_combine, verify_crc64, CRC64_HEADER,
+    };
+    use crate::request::ErrNo;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_crc64() {
+        assert_eq!(crc64(b""), 0);
+        assert_eq!(crc64(b"123456789"), 0x995DC9BBDF1939FA);
+        let data: Vec<u8> = (0..10000u32).map(|x| (x % 251) as u8).collect();
+        let (a, b) = data.split_at(3333);
+        let (b, c) = b.split_at(5000);
+        let crc = crc64_combine(crc64(a), crc64(b), b.len() as u64);
+        let crc = crc64_combine(crc, crc64(c), c.len() as u64);
+        assert_eq!(crc, crc64(&data));
+        assert_eq!(crc64_combine(crc64(a), crc64(b""), 0), crc64(a));
+    }
+
+    #[tokio::test]
+    async fn test_checksum_reader() {
+        let data: Vec<u8> = (0..200000u32).map(|x| (x % 251) as u8).collect();
+        let (md5, crc) = checksum_reader(&data[..]).await.unwrap();
+        assert_eq!(md5, content_md5(&data));
+        assert_eq!(crc, crc64(&data));
+    }
+
+    #[test]
+    fn test_verify_crc64() {
+        let mut headers = HashMap::new();
+        assert!(verify_crc64(&headers, 1).is_ok());
+        headers.insert(CRC64_HEADER.to_string(), crc64(b"123456789").to_string());
+        assert!(verify_crc64(&headers, crc64(b"123456789")).is_ok());
+        assert_eq!(
+            verify_crc64(&headers, 1).unwrap_err().error_no,
+            ErrNo::INTEGRITY
+        );
+    }
+}
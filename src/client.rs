@@ -1,6 +1,6 @@
 //! 接口客户端，所有的操作都基于该对象
 use crate::acl::AclHeader;
-use crate::request::Response;
+use crate::request::{ErrNo, Response};
 use crate::signer::Signer;
 
 /// 接口请求Client
@@ -20,6 +20,7 @@ pub struct Client {
     secrect_key: String,
     bucket: String,
     region: String,
+    integrity_check: bool,
 }
 
 impl Client {
@@ -34,13 +35,40 @@ impl Client {
             secrect_key: secrect_key.into(),
             bucket: bucket.into(),
             region: region.into(),
+            integrity_check: false,
         }
     }
 
+    /// 开启或关闭完整性校验，默认关闭
+    /// 开启后上传时发送`Content-MD5`请求头，并使用CRC64-ECMA校验上传及下载的数据，不一致时返回`ErrNo::INTEGRITY`
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// let mut client = Client::new("secrect_id", "secrect_key", "bucket", "region");
+    /// client.set_integrity_check(true);
+    /// assert!(client.get_integrity_check());
+    /// ```
+    pub fn set_integrity_check(&mut self, enable: bool) -> &mut Self {
+        self.integrity_check = enable;
+        self
+    }
+
+    pub fn get_integrity_check(&self) -> bool {
+        self.integrity_check
+    }
+
     pub fn get_host(&self) -> String {
         format!("{}.cos.{}.myqcloud.com", self.bucket, self.region)
     }
 
+    pub fn get_bucket(&self) -> &str {
+        &self.bucket
+    }
+
+    pub fn get_region(&self) -> &str {
+        &self.region
+    }
+
     pub fn get_secrect_key(&self) -> &str {
         &self.secrect_key
     }
@@ -111,6 +139,19 @@ impl Client {
         resp.unwrap_or_else(|x| x)
     }
 
+    /// 将接口返回的xml解析为`T`, 请求失败或解析失败时返回`Err(Response)`
+    pub fn make_xml_response<T: serde::de::DeserializeOwned>(
+        &self,
+        resp: Result<Response, Response>,
+    ) -> Result<T, Response> {
+        let resp = self.make_response(resp);
+        if resp.error_no != ErrNo::SUCCESS {
+            return Err(resp);
+        }
+        quick_xml::de::from_reader(&resp.result[..])
+            .map_err(|e| Response::new(ErrNo::DECODE, e.to_string(), resp.result.clone()))
+    }
+
     /// 获取预签名下载URL
     /// <https://cloud.tencent.com/document/product/436/35153>
     pub fn get_presigned_download_url(&self, object_key: &str, expire: u32) -> String {
@@ -0,0 +1,466 @@
+//! 分块复制及修改对象的存储类型
+//!
+//! 修改存储类型通过将对象复制到自身实现，元数据保持不变；
+//! 超过5GB的对象无法通过一次请求复制，会自动使用分块复制。
+use std::collections::HashMap;
+
+use chrono::{Duration, Utc};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::Body;
+use tokio::task::JoinSet;
+
+use crate::client::Client;
+use crate::objects::{get_max_threads, get_part_size, StorageClassEnum};
+use crate::request::{
+    CopyPartResult, ErrNo, ListBucketResult, ObjectSummary, Part, Request, Response,
+};
+
+/// 单次请求可复制的最大对象大小5GB
+pub const COPY_MAX_SIZE: u64 = 5 * 1024 * 1024 * 1024;
+
+/// 复制时需要保留的元数据请求头，以及`x-cos-meta-`开头的自定义元数据
+const METADATA_HEADERS: [&str; 6] = [
+    "content-type",
+    "cache-control",
+    "content-disposition",
+    "content-encoding",
+    "content-language",
+    "expires",
+];
+
+/// 批量修改存储类型时筛选对象的条件，条件都满足的对象才会修改
+#[derive(Debug, Clone, Default)]
+pub struct StorageClassFilter {
+    /// 最后修改时间早于N天前
+    pub older_than_days: Option<u32>,
+    /// 对象大小大于X字节
+    pub larger_than: Option<u64>,
+}
+
+impl StorageClassFilter {
+    /// 判断对象是否满足条件
+    /// # Examples
+    /// ```
+    /// use qcos::copy::StorageClassFilter;
+    /// use qcos::request::ObjectSummary;
+    /// use chrono::{Duration, Utc};
+    /// let filter = StorageClassFilter { older_than_days: Some(30), larger_than: Some(1024) };
+    /// let mut object = ObjectSummary {
+    ///     key: "a.log".to_string(),
+    ///     last_modified: Utc::now() - Duration::days(31),
+    ///     etag: String::new(),
+    ///     size: 2048,
+    ///     storage_class: Default::default(),
+    ///     owner: Default::default(),
+    /// };
+    /// assert!(filter.matches(&object));
+    /// object.size = 1024;
+    /// assert!(!filter.matches(&object));
+    /// ```
+    pub fn matches(&self, object: &ObjectSummary) -> bool {
+        if let Some(days) = self.older_than_days {
+            if object.last_modified > Utc::now() - Duration::days(days as i64) {
+                return false;
+            }
+        }
+        if let Some(size) = self.larger_than {
+            if object.size <= size {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// 从HEAD请求返回的headers中取出需要保留的元数据
+fn get_metadata_headers(headers: &HashMap<String, String>) -> HeaderMap {
+    let mut metadata = HeaderMap::new();
+    for (k, v) in headers {
+        if METADATA_HEADERS.contains(&k.as_str()) || k.starts_with("x-cos-meta-") {
+            if let (Ok(k), Ok(v)) = (HeaderName::try_from(k), HeaderValue::from_str(v)) {
+                metadata.insert(k, v);
+            }
+        }
+    }
+    metadata
+}
+
+/// 写入`x-cos-copy-source-If-Match`，源对象的ETag与`etag`不一致时复制返回412
+fn insert_copy_source_if_match(
+    headers: &mut HeaderMap,
+    etag: Option<&str>,
+) -> Result<(), Response> {
+    if let Some(etag) = etag {
+        let value = HeaderValue::from_str(etag).map_err(|_| {
+            Response::new(
+                ErrNo::DECODE,
+                format!("源对象的ETag不合法: {:?}", etag),
+                Default::default(),
+            )
+        })?;
+        headers.insert(HeaderName::from_static("x-cos-copy-source-if-match"), value);
+    }
+    Ok(())
+}
+
+impl Client {
+    /// 复制源对象的一个范围作为分片
+    /// <https://cloud.tencent.com/document/product/436/8287>
+    /// # 参数
+    /// - key: 目标对象的key
+    /// - upload_id: 分块上传的UploadId
+    /// - part_number: 分片号，1-10000之间
+    /// - source_key: 源对象的key
+    /// - source_version_id: 源对象的版本ID，不传表示最新版本
+    /// - range: 复制源对象的字节范围`(first, last)`，均包含
+    pub async fn upload_part_copy(
+        &self,
+        key: &str,
+        upload_id: &str,
+        part_number: u64,
+        source_key: &str,
+        source_version_id: Option<&str>,
+        range: (u64, u64),
+    ) -> Result<Part, Response> {
+        let headers = self.part_copy_headers(source_key, source_version_id, None, range)?;
+        self.put_part_copy(key, upload_id, part_number, headers)
+            .await
+    }
+
+    /// 生成分片复制的请求头，`source_etag`不为空时带上`x-cos-copy-source-If-Match`
+    fn part_copy_headers(
+        &self,
+        source_key: &str,
+        source_version_id: Option<&str>,
+        source_etag: Option<&str>,
+        range: (u64, u64),
+    ) -> Result<HeaderMap, Response> {
+        let mut headers = self.get_common_headers();
+        headers.insert(
+            HeaderName::from_static("x-cos-copy-source"),
+            HeaderValue::from_str(&self.get_copy_source(source_key, source_version_id)).unwrap(),
+        );
+        headers.insert(
+            HeaderName::from_static("x-cos-copy-source-range"),
+            HeaderValue::from_str(&format!("bytes={}-{}", range.0, range.1)).unwrap(),
+        );
+        insert_copy_source_if_match(&mut headers, source_etag)?;
+        Ok(headers)
+    }
+
+    /// 发送分片复制请求，`headers`由[`part_copy_headers`](Self::part_copy_headers)生成
+    async fn put_part_copy(
+        &self,
+        key: &str,
+        upload_id: &str,
+        part_number: u64,
+        headers: HeaderMap,
+    ) -> Result<Part, Response> {
+        let url_path = self.get_path_from_object_key(key);
+        let mut query = HashMap::new();
+        query.insert("partNumber".to_string(), part_number.to_string());
+        query.insert("uploadId".to_string(), upload_id.to_string());
+        let headers = self.get_headers_with_auth(
+            "put",
+            url_path.as_str(),
+            None,
+            Some(headers),
+            Some(query.clone()),
+        );
+        let resp = Request::put(
+            self.get_full_url_from_path(url_path.as_str()).as_str(),
+            Some(&query),
+            Some(&headers),
+            None,
+            None,
+            None as Option<Body>,
+        )
+        .await;
+        let result: CopyPartResult = self.make_xml_response(resp)?;
+        Ok(Part {
+            part_number,
+            etag: result.etag,
+        })
+    }
+
+    /// 分块复制对象，`metadata`为目标对象的元数据及存储类型等请求头，失败时清理已复制的分片；
+    /// 各分片带上源对象的`source_etag`，复制过程中源对象被覆盖时返回412，不会合并出新旧混合的对象
+    async fn copy_object_multipart(
+        &self,
+        key: &str,
+        source_key: &str,
+        source_etag: Option<&str>,
+        size: u64,
+        metadata: HeaderMap,
+        max_threads: Option<u64>,
+    ) -> Result<(), Response> {
+        let part_size = get_part_size(size, None)?;
+        let max_threads = get_max_threads(max_threads)?;
+        let mut headers = self.get_common_headers();
+        headers.extend(metadata);
+        let resp = self.initiate_multipart_upload(key, headers, None).await;
+        if resp.error_no != ErrNo::SUCCESS {
+            return Err(resp);
+        }
+        let upload_id = String::from_utf8_lossy(&resp.result[..]).to_string();
+        let mut etag_map = HashMap::new();
+        let mut tasks = JoinSet::new();
+        let mut offset = 0;
+        let mut part_number = 1;
+        while offset < size || !tasks.is_empty() {
+            if offset < size && tasks.len() < max_threads {
+                let last = (offset + part_size).min(size) - 1;
+                let headers =
+                    match self.part_copy_headers(source_key, None, source_etag, (offset, last)) {
+                        Ok(headers) => headers,
+                        Err(e) => {
+                            tasks.abort_all();
+                            self.abort_object_part(key, &upload_id).await;
+                            return Err(e);
+                        }
+                    };
+                let this = self.clone();
+                let key = key.to_string();
+                let upload_id = upload_id.clone();
+                tasks.spawn(async move {
+                    this.put_part_copy(&key, &upload_id, part_number, headers)
+                        .await
+                });
+                offset = last + 1;
+                part_number += 1;
+                continue;
+            }
+            let result = match tasks.join_next().await {
+                Some(Ok(result)) => result,
+                Some(Err(e)) => Err(Response::new(
+                    ErrNo::OTHER,
+                    format!("分片复制任务异常: {}", e),
+                    Default::default(),
+                )),
+                None => break,
+            };
+            match result {
+                Ok(part) => {
+                    etag_map.insert(part.part_number, part.etag);
+                }
+                Err(e) => {
+                    tasks.abort_all();
+                    self.abort_object_part(key, &upload_id).await;
+                    return Err(e);
+                }
+            }
+        }
+        let resp = self
+            .put_object_complete_part(key, etag_map, &upload_id)
+            .await;
+        if resp.error_no != ErrNo::SUCCESS {
+            self.abort_object_part(key, &upload_id).await;
+            return Err(resp);
+        }
+        Ok(())
+    }
+
+    /// 根据HEAD请求返回的headers修改对象的存储类型，已经是目标存储类型时不做处理
+    async fn change_storage_class(
+        &self,
+        key: &str,
+        storage_class: &StorageClassEnum,
+        headers: &HashMap<String, String>,
+        max_threads: Option<u64>,
+    ) -> Result<(), Response> {
+        if &StorageClassEnum::from_headers(headers) == storage_class {
+            return Ok(());
+        }
+        let size: u64 = match headers.get("content-length").map(|x| x.parse()) {
+            Some(Ok(size)) => size,
+            _ => {
+                return Err(Response::new(
+                    ErrNo::DECODE,
+                    format!("对象{}的content-length缺失或不合法", key),
+                    Default::default(),
+                ))
+            }
+        };
+        // 只在对象未被覆盖时复制，避免把新对象改为目标存储类型或用旧内容覆盖新对象；
+        // 不指定源版本，否则对象被覆盖后仍会复制旧版本
+        let etag = headers.get("etag").map(|x| x.as_str());
+        let mut metadata = HeaderMap::new();
+        metadata.insert(
+            HeaderName::from_static("x-cos-storage-class"),
+            HeaderValue::from_str(&storage_class.to_string()).unwrap(),
+        );
+        if size <= COPY_MAX_SIZE {
+            // 复制到自身时保留元数据
+            metadata.insert(
+                HeaderName::from_static("x-cos-metadata-directive"),
+                HeaderValue::from_static("Copy"),
+            );
+            let mut headers = self.get_common_headers();
+            headers.extend(metadata);
+            insert_copy_source_if_match(&mut headers, etag)?;
+            self.copy_object_with_headers(key, key, None, headers, None)
+                .await?;
+            return Ok(());
+        }
+        // 分块复制不会自动复制元数据，需要在初始化时带上
+        metadata.extend(get_metadata_headers(headers));
+        self.copy_object_multipart(key, key, etag, size, metadata, max_threads)
+            .await
+    }
+
+    /// 修改对象的存储类型，不需要重新上传，元数据保持不变
+    /// <https://cloud.tencent.com/document/product/436/10881>
+    /// 超过5GB的对象会使用分块复制；归档对象需要先恢复才能修改
+    /// 修改过程中对象被覆盖时返回`ErrNo::PRECONDITION_FAILED`，不会修改新对象
+    /// # 参数
+    /// - key: 对象的key
+    /// - storage_class: 目标存储类型
+    /// - max_threads: 分块复制的最大线程数，要求1-1000之间，默认20
+    ///
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// use qcos::objects::StorageClassEnum;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let res = client.set_object_storage_class("Cargo.toml", StorageClassEnum::StandardIa, None).await;
+    /// };
+    /// ```
+    pub async fn set_object_storage_class(
+        &self,
+        key: &str,
+        storage_class: StorageClassEnum,
+        max_threads: Option<u64>,
+    ) -> Result<(), Response> {
+        let resp = self.head_object(key).await;
+        if resp.error_no != ErrNo::SUCCESS {
+            return Err(resp);
+        }
+        self.change_storage_class(key, &storage_class, &resp.headers, max_threads)
+            .await
+    }
+
+    /// 批量修改前缀为`prefix`且满足`filter`条件的对象的存储类型，返回修改过的对象
+    /// 已经是目标存储类型的对象会跳过，遇到错误时停止并返回错误
+    /// `max_threads`为分块复制的最大线程数，要求1-1000之间，默认20
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// use qcos::copy::StorageClassFilter;
+    /// use qcos::objects::StorageClassEnum;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// // 将logs/下30天前且大于1MB的对象转为归档存储
+    /// let filter = StorageClassFilter { older_than_days: Some(30), larger_than: Some(1024 * 1024) };
+    /// let res = client.set_objects_storage_class("logs/", StorageClassEnum::ARCHIVE, &filter, None).await;
+    /// };
+    /// ```
+    pub async fn set_objects_storage_class(
+        &self,
+        prefix: &str,
+        storage_class: StorageClassEnum,
+        filter: &StorageClassFilter,
+        max_threads: Option<u64>,
+    ) -> Result<Vec<ObjectSummary>, Response> {
+        // 提前校验，避免修改部分对象后才因参数错误中断
+        get_max_threads(max_threads)?;
+        let mut changed = Vec::new();
+        let mut marker = String::new();
+        loop {
+            let resp = self.list_objects(prefix, "", "", &marker, 1000).await;
+            let result: ListBucketResult = self.make_xml_response(Ok(resp))?;
+            for object in result.contents {
+                if object.storage_class == storage_class || !filter.matches(&object) {
+                    continue;
+                }
+                let resp = self.head_object(&object.key).await;
+                if resp.error_no != ErrNo::SUCCESS {
+                    return Err(resp);
+                }
+                self.change_storage_class(&object.key, &storage_class, &resp.headers, max_threads)
+                    .await?;
+                changed.push(object);
+            }
+            if !result.is_truncated {
+                break;
+            }
+            marker = result.next_marker;
+        }
+        Ok(changed)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::client::Client;
+    use crate::copy::{get_metadata_headers, StorageClassFilter};
+    use crate::objects::StorageClassEnum;
+    use crate::request::ErrNo;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_get_metadata_headers() {
+        let mut headers = HashMap::new();
+        headers.insert("content-type".to_string(), "text/plain".to_string());
+        headers.insert("x-cos-meta-author".to_string(), "qcos".to_string());
+        headers.insert("x-cos-storage-class".to_string(), "ARCHIVE".to_string());
+        headers.insert("etag".to_string(), "\"abc\"".to_string());
+        let metadata = get_metadata_headers(&headers);
+        assert_eq!(metadata.len(), 2);
+        assert_eq!(metadata["content-type"], "text/plain");
+        assert_eq!(metadata["x-cos-meta-author"], "qcos");
+    }
+
+    #[test]
+    fn test_part_copy_headers() {
+        let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+        let etag = "\"e0ad4fa8d1f4a2ab8a8ba1ab0e6b7a4e\"";
+        let headers = client
+            .part_copy_headers("a.txt", None, Some(etag), (0, 1023))
+            .unwrap();
+        assert_eq!(headers["x-cos-copy-source-if-match"], etag);
+        assert_eq!(headers["x-cos-copy-source-range"], "bytes=0-1023");
+        assert!(headers["x-cos-copy-source"]
+            .to_str()
+            .unwrap()
+            .ends_with("/a.txt"));
+
+        let headers = client
+            .part_copy_headers("a.txt", None, None, (0, 1023))
+            .unwrap();
+        assert!(headers.get("x-cos-copy-source-if-match").is_none());
+        let err = client
+            .part_copy_headers("a.txt", None, Some("\"abc\"\n"), (0, 1023))
+            .unwrap_err();
+        assert_eq!(err.error_no, ErrNo::DECODE);
+    }
+
+    #[tokio::test]
+    async fn test_change_storage_class_invalid_params() {
+        let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+        let mut headers = HashMap::new();
+        headers.insert("x-cos-storage-class".to_string(), "STANDARD".to_string());
+        let err = client
+            .change_storage_class("a.txt", &StorageClassEnum::ARCHIVE, &headers, None)
+            .await
+            .unwrap_err();
+        assert_eq!(err.error_no, ErrNo::DECODE);
+        headers.insert("content-length".to_string(), "abc".to_string());
+        let err = client
+            .change_storage_class("a.txt", &StorageClassEnum::ARCHIVE, &headers, None)
+            .await
+            .unwrap_err();
+        assert_eq!(err.error_no, ErrNo::DECODE);
+
+        let err = client
+            .set_objects_storage_class(
+                "logs/",
+                StorageClassEnum::ARCHIVE,
+                &StorageClassFilter::default(),
+                Some(0),
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(err.error_no, ErrNo::PARAM);
+    }
+}
@@ -0,0 +1,602 @@
+//! 清单
+//!
+//! 清单按天或按周将存储桶中的对象列表以CSV格式投递到目标存储桶，
+//! 每次投递包含一个`manifest.json`及若干gzip压缩的CSV文件，
+//! 解压后可以通过[`InventoryManifest`]及[`parse_inventory_csv`]解析。
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+
+use crate::bucket::resource_query;
+use crate::client::Client;
+use crate::objects::StorageClassEnum;
+use crate::request::{ErrNo, Response};
+
+/// 投递频率
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Copy)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+}
+
+/// 清单包含的对象版本
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Copy)]
+pub enum IncludedObjectVersions {
+    /// 所有版本，清单中会增加`VersionId`、`IsLatest`、`IsDeleteMarker`列
+    All,
+    /// 仅最新版本
+    Current,
+}
+
+/// 清单文件格式
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Copy)]
+pub enum InventoryFormat {
+    CSV,
+}
+
+/// 清单中可选的字段
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub enum InventoryField {
+    Size,
+    LastModifiedDate,
+    ETag,
+    StorageClass,
+    IsMultipartUploaded,
+    ReplicationStatus,
+    /// 未知的取值，保留原始字符串
+    Unknown(String),
+}
+
+impl Display for InventoryField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            InventoryField::Size => "Size",
+            InventoryField::LastModifiedDate => "LastModifiedDate",
+            InventoryField::ETag => "ETag",
+            InventoryField::StorageClass => "StorageClass",
+            InventoryField::IsMultipartUploaded => "IsMultipartUploaded",
+            InventoryField::ReplicationStatus => "ReplicationStatus",
+            InventoryField::Unknown(s) => s,
+        };
+        f.write_str(s)
+    }
+}
+
+impl FromStr for InventoryField {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "Size" => InventoryField::Size,
+            "LastModifiedDate" => InventoryField::LastModifiedDate,
+            "ETag" => InventoryField::ETag,
+            "StorageClass" => InventoryField::StorageClass,
+            "IsMultipartUploaded" => InventoryField::IsMultipartUploaded,
+            "ReplicationStatus" => InventoryField::ReplicationStatus,
+            _ => InventoryField::Unknown(s.to_string()),
+        })
+    }
+}
+
+// 按字符串序列化，否则quick-xml会把枚举值当作元素名
+impl serde::Serialize for InventoryField {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for InventoryField {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        // 未知的取值解析为`Unknown`，不会失败
+        Ok(s.parse().unwrap())
+    }
+}
+
+/// SSE-COS加密，没有内容
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Default)]
+pub struct SseCos {}
+
+/// 清单文件的加密方式
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Default)]
+pub struct InventoryEncryption {
+    #[serde(rename = "SSE-COS", default, skip_serializing_if = "Option::is_none")]
+    pub sse_cos: Option<SseCos>,
+}
+
+/// 清单投递的目标存储桶
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct COSBucketDestination {
+    #[serde(rename = "Format")]
+    pub format: InventoryFormat,
+    /// 目标存储桶所有者的主账号ID
+    #[serde(rename = "AccountId", default, skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<String>,
+    /// 格式为`qcs::cos:<Region>::<BucketName-APPID>`
+    #[serde(rename = "Bucket")]
+    pub bucket: String,
+    #[serde(rename = "Prefix", default, skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<String>,
+    #[serde(
+        rename = "Encryption",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub encryption: Option<InventoryEncryption>,
+}
+
+impl COSBucketDestination {
+    /// 投递到`region`地域的`bucket`存储桶中前缀为`prefix`的位置
+    pub fn new(region: &str, bucket: &str, prefix: &str) -> Self {
+        Self {
+            format: InventoryFormat::CSV,
+            account_id: None,
+            bucket: format!("qcs::cos:{}::{}", region, bucket),
+            prefix: (!prefix.is_empty()).then(|| prefix.to_string()),
+            encryption: None,
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct InventoryDestination {
+    #[serde(rename = "COSBucketDestination")]
+    pub cos_bucket_destination: COSBucketDestination,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct InventorySchedule {
+    #[serde(rename = "Frequency")]
+    pub frequency: Frequency,
+}
+
+/// 只列出前缀为`prefix`的对象
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Default)]
+pub struct InventoryFilter {
+    #[serde(rename = "Prefix", default)]
+    pub prefix: String,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Default)]
+pub struct OptionalFields {
+    #[serde(rename = "Field", default)]
+    pub field: Vec<InventoryField>,
+}
+
+/// 清单配置
+/// <https://cloud.tencent.com/document/product/436/33707>
+/// ```
+/// use qcos::inventory::{COSBucketDestination, Frequency, InventoryConfiguration, InventoryField};
+/// use quick_xml::de::from_str;
+/// use quick_xml::se::to_string;
+/// let destination = COSBucketDestination::new("ap-guangzhou", "inventory-1250000000", "list1");
+/// let mut config = InventoryConfiguration::new("list1", destination, Frequency::Daily);
+/// config.field(InventoryField::Size).field(InventoryField::ETag).filter_prefix("data/");
+/// let s = to_string(&config).unwrap();
+/// assert_eq!(s, "<InventoryConfiguration><Id>list1</Id><IsEnabled>true</IsEnabled><Destination><COSBucketDestination><Format>CSV</Format><Bucket>qcs::cos:ap-guangzhou::inventory-1250000000</Bucket><Prefix>list1</Prefix></COSBucketDestination></Destination><Schedule><Frequency>Daily</Frequency></Schedule><Filter><Prefix>data/</Prefix></Filter><IncludedObjectVersions>Current</IncludedObjectVersions><OptionalFields><Field>Size</Field><Field>ETag</Field></OptionalFields></InventoryConfiguration>");
+/// assert_eq!(from_str::<InventoryConfiguration>(&s).unwrap(), config);
+/// ```
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct InventoryConfiguration {
+    /// 清单名称
+    #[serde(rename = "Id")]
+    pub id: String,
+    #[serde(rename = "IsEnabled")]
+    pub is_enabled: bool,
+    #[serde(rename = "Destination")]
+    pub destination: InventoryDestination,
+    #[serde(rename = "Schedule")]
+    pub schedule: InventorySchedule,
+    #[serde(rename = "Filter", default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<InventoryFilter>,
+    #[serde(rename = "IncludedObjectVersions")]
+    pub included_object_versions: IncludedObjectVersions,
+    #[serde(rename = "OptionalFields", default)]
+    pub optional_fields: OptionalFields,
+}
+
+impl InventoryConfiguration {
+    /// 创建启用状态、仅包含最新版本的清单
+    pub fn new(id: &str, destination: COSBucketDestination, frequency: Frequency) -> Self {
+        Self {
+            id: id.to_string(),
+            is_enabled: true,
+            destination: InventoryDestination {
+                cos_bucket_destination: destination,
+            },
+            schedule: InventorySchedule { frequency },
+            filter: None,
+            included_object_versions: IncludedObjectVersions::Current,
+            optional_fields: OptionalFields::default(),
+        }
+    }
+
+    /// 增加一个可选字段
+    pub fn field(&mut self, field: InventoryField) -> &mut Self {
+        if !self.optional_fields.field.contains(&field) {
+            self.optional_fields.field.push(field);
+        }
+        self
+    }
+
+    /// 只列出前缀为`prefix`的对象
+    pub fn filter_prefix(&mut self, prefix: &str) -> &mut Self {
+        self.filter = Some(InventoryFilter {
+            prefix: prefix.to_string(),
+        });
+        self
+    }
+}
+
+/// 列出清单配置的结果
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct ListInventoryConfigurationResult {
+    #[serde(rename = "InventoryConfiguration", default)]
+    pub inventory_configuration: Vec<InventoryConfiguration>,
+    /// 是否还有未返回的结果，为`true`时使用`next_continuation_token`继续查询
+    #[serde(rename = "IsTruncated", default)]
+    pub is_truncated: bool,
+    #[serde(rename = "ContinuationToken", default)]
+    pub continuation_token: String,
+    #[serde(rename = "NextContinuationToken", default)]
+    pub next_continuation_token: String,
+}
+
+/// 清单中的单个文件
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct InventoryManifestFile {
+    pub key: String,
+    #[serde(default)]
+    pub size: u64,
+    #[serde(rename = "MD5checksum", default)]
+    pub md5_checksum: String,
+}
+
+/// 每次投递生成的`manifest.json`
+/// ```
+/// use qcos::inventory::InventoryManifest;
+/// let s = r#"{"sourceBucket":"examplebucket-1250000000","destinationBucket":"qcs::cos:ap-guangzhou::inventory-1250000000","version":"2019-07-01","creationTimestamp":"1561543200","fileFormat":"CSV","fileSchema":"Region, Bucket, Key, Size, ETag","files":[{"key":"list1/examplebucket-1250000000/list1/20190626/0.csv.gz","size":1024,"MD5checksum":"6bca4ee0e9bc2d0d1b4d1c9a4f1c2f5d"}]}"#;
+/// let manifest = InventoryManifest::from_slice(s.as_bytes()).unwrap();
+/// assert_eq!(manifest.schema(), vec!["Region", "Bucket", "Key", "Size", "ETag"]);
+/// assert_eq!(manifest.files[0].size, 1024);
+/// ```
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InventoryManifest {
+    pub source_bucket: String,
+    pub destination_bucket: String,
+    #[serde(default)]
+    pub version: String,
+    /// 生成时间的Unix时间戳(秒)
+    #[serde(default)]
+    pub creation_timestamp: String,
+    pub file_format: String,
+    /// 以逗号分隔的列名
+    pub file_schema: String,
+    #[serde(default)]
+    pub files: Vec<InventoryManifestFile>,
+}
+
+impl InventoryManifest {
+    /// 解析`manifest.json`的内容
+    pub fn from_slice(data: &[u8]) -> Result<Self, Response> {
+        serde_json::from_slice(data)
+            .map_err(|e| Response::new(ErrNo::DECODE, e.to_string(), data.to_vec()))
+    }
+
+    /// CSV文件的列名
+    pub fn schema(&self) -> Vec<&str> {
+        self.file_schema.split(',').map(|x| x.trim()).collect()
+    }
+}
+
+/// 清单CSV中的一行，没有对应列时为`None`
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct InventoryRecord {
+    pub region: String,
+    pub bucket: String,
+    /// 已URL解码的对象键
+    pub key: String,
+    pub version_id: Option<String>,
+    pub is_latest: Option<bool>,
+    pub is_delete_marker: Option<bool>,
+    pub size: Option<u64>,
+    pub last_modified: Option<DateTime<Utc>>,
+    pub etag: Option<String>,
+    pub storage_class: Option<StorageClassEnum>,
+    pub is_multipart_uploaded: Option<bool>,
+    pub replication_status: Option<String>,
+}
+
+/// 拆分CSV的一行，字段可以用双引号包裹，双引号内的`""`表示一个双引号
+fn split_csv_line(line: &str) -> Result<Vec<String>, String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut chars = line.chars().peekable();
+    let mut quoted = false;
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted => {
+                if chars.peek() == Some(&'"') {
+                    field.push('"');
+                    chars.next();
+                } else {
+                    quoted = false;
+                }
+            }
+            '"' if field.is_empty() => quoted = true,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+    if quoted {
+        return Err(format!("引号未闭合: {}", line));
+    }
+    fields.push(field);
+    Ok(fields)
+}
+
+/// 按`schema`解析解压后的清单CSV文件，`schema`为[`InventoryManifest::schema`]的返回
+/// # Examples
+/// ```
+/// use qcos::inventory::parse_inventory_csv;
+/// use qcos::objects::StorageClassEnum;
+/// let data = "\"ap-guangzhou\",\"examplebucket-1250000000\",\"docs%2Fa%20b.txt\",\"1024\",\"2019-06-26T09:00:00.000Z\",\"STANDARD_IA\"\n";
+/// let schema = ["Region", "Bucket", "Key", "Size", "LastModifiedDate", "StorageClass"];
+/// let records = parse_inventory_csv(&schema, data.as_bytes()).unwrap();
+/// assert_eq!(records[0].key, "docs/a b.txt");
+/// assert_eq!(records[0].size, Some(1024));
+/// assert_eq!(records[0].storage_class, Some(StorageClassEnum::StandardIa));
+/// assert_eq!(records[0].etag, None);
+/// ```
+pub fn parse_inventory_csv(schema: &[&str], data: &[u8]) -> Result<Vec<InventoryRecord>, Response> {
+    let decode_error = |message: String| Response::new(ErrNo::DECODE, message, Vec::new());
+    if data.starts_with(&[0x1f, 0x8b]) {
+        return Err(Response::new(
+            ErrNo::PARAM,
+            "清单文件为gzip格式，需要先解压".to_string(),
+            Vec::new(),
+        ));
+    }
+    let text = std::str::from_utf8(data).map_err(|e| decode_error(e.to_string()))?;
+    let mut records = Vec::new();
+    for line in text.lines().filter(|x| !x.trim().is_empty()) {
+        let values = split_csv_line(line.trim_end_matches('\r')).map_err(decode_error)?;
+        if values.len() != schema.len() {
+            return Err(decode_error(format!(
+                "列数{}与清单格式的列数{}不一致: {}",
+                values.len(),
+                schema.len(),
+                line
+            )));
+        }
+        let row: HashMap<&str, String> = schema.iter().copied().zip(values).collect();
+        let get = |name: &str| row.get(name).filter(|x| !x.is_empty()).cloned();
+        let get_bool = |name: &str| get(name).map(|x| x.eq_ignore_ascii_case("true"));
+        let key = get("Key").unwrap_or_default();
+        records.push(InventoryRecord {
+            region: get("Region").unwrap_or_default(),
+            bucket: get("Bucket").unwrap_or_default(),
+            key: urlencoding::decode(&key)
+                .map_err(|e| decode_error(e.to_string()))?
+                .into_owned(),
+            version_id: get("VersionId"),
+            is_latest: get_bool("IsLatest"),
+            is_delete_marker: get_bool("IsDeleteMarker"),
+            size: match get("Size") {
+                Some(x) => Some(
+                    x.parse()
+                        .map_err(|_| decode_error(format!("无法解析的大小: {}", x)))?,
+                ),
+                None => None,
+            },
+            last_modified: match get("LastModifiedDate") {
+                Some(x) => Some(
+                    DateTime::parse_from_rfc3339(&x)
+                        .map_err(|_| decode_error(format!("无法解析的时间: {}", x)))?
+                        .with_timezone(&Utc),
+                ),
+                None => None,
+            },
+            etag: get("ETag"),
+            storage_class: get("StorageClass").map(|x| x.parse().unwrap()),
+            is_multipart_uploaded: get_bool("IsMultipartUploaded"),
+            replication_status: get("ReplicationStatus"),
+        });
+    }
+    Ok(records)
+}
+
+/// 清单配置的查询参数，如`?inventory&id=list1`
+fn inventory_query(id: &str) -> HashMap<String, String> {
+    let mut query = resource_query("inventory");
+    query.insert("id".to_string(), id.to_string());
+    query
+}
+
+impl Client {
+    /// 创建清单配置，同名的清单会被覆盖
+    /// <https://cloud.tencent.com/document/product/436/33707>
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// use qcos::inventory::{COSBucketDestination, Frequency, InventoryConfiguration, InventoryField};
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let destination = COSBucketDestination::new("ap-guangzhou", "inventory-1250000000", "list1");
+    /// let mut config = InventoryConfiguration::new("list1", destination, Frequency::Weekly);
+    /// config.field(InventoryField::Size).field(InventoryField::StorageClass);
+    /// let res = client.put_bucket_inventory(&config).await;
+    /// assert!(res.error_message.contains("403"));
+    /// };
+    /// ```
+    pub async fn put_bucket_inventory(&self, config: &InventoryConfiguration) -> Response {
+        if config.id.is_empty() {
+            return Response::new(
+                ErrNo::PARAM,
+                "清单名称不能为空".to_string(),
+                Default::default(),
+            );
+        }
+        self.put_bucket_config_with_query(inventory_query(&config.id), config)
+            .await
+    }
+
+    /// 查询指定名称的清单配置
+    /// <https://cloud.tencent.com/document/product/436/33705>
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// if let Ok(config) = client.get_bucket_inventory("list1").await {
+    ///     println!("{:?}", config.schedule.frequency);
+    /// }
+    /// };
+    /// ```
+    pub async fn get_bucket_inventory(&self, id: &str) -> Result<InventoryConfiguration, Response> {
+        self.get_bucket_config_with_query(inventory_query(id)).await
+    }
+
+    /// 分页列出存储桶的全部清单配置，首次查询`continuation_token`传空字符串
+    /// <https://cloud.tencent.com/document/product/436/33706>
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let mut token = String::new();
+    /// while let Ok(res) = client.list_bucket_inventory(&token).await {
+    ///     for config in res.inventory_configuration {
+    ///         println!("{}", config.id);
+    ///     }
+    ///     if !res.is_truncated {
+    ///         break;
+    ///     }
+    ///     token = res.next_continuation_token;
+    /// }
+    /// };
+    /// ```
+    pub async fn list_bucket_inventory(
+        &self,
+        continuation_token: &str,
+    ) -> Result<ListInventoryConfigurationResult, Response> {
+        let mut query = resource_query("inventory");
+        if !continuation_token.is_empty() {
+            query.insert(
+                "continuation-token".to_string(),
+                continuation_token.to_string(),
+            );
+        }
+        self.get_bucket_config_with_query(query).await
+    }
+
+    /// 删除指定名称的清单配置
+    /// <https://cloud.tencent.com/document/product/436/33704>
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let res = client.delete_bucket_inventory("list1").await;
+    /// assert!(res.error_message.contains("403"));
+    /// };
+    /// ```
+    pub async fn delete_bucket_inventory(&self, id: &str) -> Response {
+        self.delete_bucket_config_with_query(inventory_query(id))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::inventory::{
+        parse_inventory_csv, split_csv_line, IncludedObjectVersions, InventoryField,
+        ListInventoryConfigurationResult,
+    };
+    use crate::request::ErrNo;
+
+    #[test]
+    fn test_split_csv_line() {
+        assert_eq!(
+            split_csv_line(r#""a","b ""c""",d,"#).unwrap(),
+            vec!["a", r#"b "c""#, "d", ""]
+        );
+        assert!(split_csv_line(r#""a"#).is_err());
+    }
+
+    #[test]
+    fn test_parse_inventory_csv() {
+        let schema = [
+            "Region",
+            "Bucket",
+            "Key",
+            "VersionId",
+            "IsLatest",
+            "IsDeleteMarker",
+            "Size",
+            "ETag",
+            "IsMultipartUploaded",
+        ];
+        let data = concat!(
+            "\"ap-guangzhou\",\"examplebucket-1250000000\",\"a.txt\",\"MTg0NDUxNTc1NjIzMTQ1MDAwODg\",\"true\",\"false\",\"10\",\"\"\"abc\"\"\",\"false\"\r\n",
+            "\"ap-guangzhou\",\"examplebucket-1250000000\",\"a.txt\",\"MTg0NDUxNTc1NjIzMTQ1MDAwODk\",\"false\",\"true\",\"\",\"\",\"\"\r\n",
+        );
+        let records = parse_inventory_csv(&schema, data.as_bytes()).unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].is_latest, Some(true));
+        assert_eq!(records[0].etag.as_deref(), Some("\"abc\""));
+        assert_eq!(records[0].is_multipart_uploaded, Some(false));
+        assert_eq!(records[1].is_delete_marker, Some(true));
+        assert_eq!(records[1].size, None);
+
+        let err = parse_inventory_csv(&schema[..2], data.as_bytes()).unwrap_err();
+        assert_eq!(err.error_no, ErrNo::DECODE);
+        let err = parse_inventory_csv(&schema, &[0x1f, 0x8b, 0x08]).unwrap_err();
+        assert_eq!(err.error_no, ErrNo::PARAM);
+    }
+
+    #[test]
+    fn test_list_inventory_configuration() {
+        let xml = r#"<ListInventoryConfigurationResult>
+            <InventoryConfiguration>
+                <Id>list1</Id>
+                <IsEnabled>false</IsEnabled>
+                <Destination>
+                    <COSBucketDestination>
+                        <Format>CSV</Format>
+                        <AccountId>100000000001</AccountId>
+                        <Bucket>qcs::cos:ap-guangzhou::inventory-1250000000</Bucket>
+                        <Encryption><SSE-COS></SSE-COS></Encryption>
+                    </COSBucketDestination>
+                </Destination>
+                <Schedule><Frequency>Weekly</Frequency></Schedule>
+                <IncludedObjectVersions>All</IncludedObjectVersions>
+                <OptionalFields><Field>Size</Field><Field>Tag</Field></OptionalFields>
+            </InventoryConfiguration>
+            <IsTruncated>false</IsTruncated>
+        </ListInventoryConfigurationResult>"#;
+        let res: ListInventoryConfigurationResult = quick_xml::de::from_str(xml).unwrap();
+        let config = &res.inventory_configuration[0];
+        assert!(!config.is_enabled);
+        assert_eq!(config.included_object_versions, IncludedObjectVersions::All);
+        assert!(config
+            .destination
+            .cos_bucket_destination
+            .encryption
+            .as_ref()
+            .unwrap()
+            .sse_cos
+            .is_some());
+        assert_eq!(
+            config.optional_fields.field,
+            vec![
+                InventoryField::Size,
+                InventoryField::Unknown("Tag".to_string())
+            ]
+        );
+    }
+}
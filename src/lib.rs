@@ -21,8 +21,18 @@ async fn main() {
 
 pub mod acl;
 pub mod bucket;
+pub mod checksum;
 pub mod client;
+pub mod copy;
+pub mod inventory;
+pub mod lifecycle;
+pub mod multipart;
 pub mod objects;
+pub mod options;
+pub mod policy;
+pub mod replication;
 pub mod request;
+pub mod restore;
 pub mod service;
 pub mod signer;
+pub mod tagging;
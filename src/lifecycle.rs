@@ -0,0 +1,439 @@
+//! 生命周期
+//!
+//! 生命周期规则可以按前缀或标签筛选对象，在指定天数后转换存储类型或删除，
+//! 也可以清理长期未完成的分块上传，避免失败的上传残留分片占用存储空间。
+use chrono::{DateTime, Utc};
+
+use crate::client::Client;
+use crate::objects::StorageClassEnum;
+use crate::request::{ErrNo, Response};
+use crate::tagging::Tag;
+
+/// 单个存储桶最多1000条规则
+pub const MAX_LIFECYCLE_RULES: usize = 1000;
+
+/// 规则的状态
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Copy)]
+pub enum RuleStatus {
+    Enabled,
+    Disabled,
+}
+
+/// 前缀和标签同时筛选
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Default)]
+pub struct LifecycleAnd {
+    #[serde(rename = "Prefix", default, skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<String>,
+    #[serde(rename = "Tag", default)]
+    pub tag: Vec<Tag>,
+}
+
+/// 规则作用的对象，都为空表示整个存储桶
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Default)]
+pub struct LifecycleFilter {
+    #[serde(rename = "Prefix", default, skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<String>,
+    #[serde(rename = "Tag", default, skip_serializing_if = "Option::is_none")]
+    pub tag: Option<Tag>,
+    #[serde(rename = "And", default, skip_serializing_if = "Option::is_none")]
+    pub and: Option<LifecycleAnd>,
+}
+
+impl LifecycleFilter {
+    /// 作用于前缀为`prefix`的对象
+    pub fn prefix(prefix: &str) -> Self {
+        Self {
+            prefix: Some(prefix.to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// 作用于前缀为`prefix`且包含全部`tags`的对象，`prefix`为空时只按标签筛选
+    pub fn tags(prefix: &str, tags: &[(&str, &str)]) -> Self {
+        let tag: Vec<Tag> = tags
+            .iter()
+            .map(|(k, v)| Tag {
+                key: k.to_string(),
+                value: v.to_string(),
+            })
+            .collect();
+        if prefix.is_empty() && tag.len() == 1 {
+            return Self {
+                tag: tag.into_iter().next(),
+                ..Default::default()
+            };
+        }
+        Self {
+            and: Some(LifecycleAnd {
+                prefix: (!prefix.is_empty()).then(|| prefix.to_string()),
+                tag,
+            }),
+            ..Default::default()
+        }
+    }
+}
+
+/// 转换存储类型，`days`与`date`二选一
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct Transition {
+    /// 对象最后修改时间之后的天数
+    #[serde(rename = "Days", default, skip_serializing_if = "Option::is_none")]
+    pub days: Option<u32>,
+    /// 指定日期之后
+    #[serde(rename = "Date", default, skip_serializing_if = "Option::is_none")]
+    pub date: Option<DateTime<Utc>>,
+    #[serde(rename = "StorageClass")]
+    pub storage_class: StorageClassEnum,
+}
+
+/// 删除对象，`days`、`date`、`expired_object_delete_marker`三选一
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Default)]
+pub struct Expiration {
+    /// 对象最后修改时间之后的天数
+    #[serde(rename = "Days", default, skip_serializing_if = "Option::is_none")]
+    pub days: Option<u32>,
+    /// 指定日期之后
+    #[serde(rename = "Date", default, skip_serializing_if = "Option::is_none")]
+    pub date: Option<DateTime<Utc>>,
+    /// 开启版本控制时，删除没有历史版本的删除标记
+    #[serde(
+        rename = "ExpiredObjectDeleteMarker",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub expired_object_delete_marker: Option<bool>,
+}
+
+/// 历史版本转换存储类型
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct NoncurrentVersionTransition {
+    /// 成为历史版本之后的天数
+    #[serde(rename = "NoncurrentDays")]
+    pub noncurrent_days: u32,
+    #[serde(rename = "StorageClass")]
+    pub storage_class: StorageClassEnum,
+}
+
+/// 删除历史版本
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct NoncurrentVersionExpiration {
+    /// 成为历史版本之后的天数
+    #[serde(rename = "NoncurrentDays")]
+    pub noncurrent_days: u32,
+}
+
+/// 清理未完成的分块上传
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct AbortIncompleteMultipartUpload {
+    /// 分块上传初始化之后的天数
+    #[serde(rename = "DaysAfterInitiation")]
+    pub days_after_initiation: u32,
+}
+
+/// 单条生命周期规则
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct LifecycleRule {
+    #[serde(rename = "ID", default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "Filter", default)]
+    pub filter: LifecycleFilter,
+    #[serde(rename = "Status")]
+    pub status: RuleStatus,
+    #[serde(rename = "Transition", default)]
+    pub transition: Vec<Transition>,
+    #[serde(
+        rename = "Expiration",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub expiration: Option<Expiration>,
+    #[serde(rename = "NoncurrentVersionTransition", default)]
+    pub noncurrent_version_transition: Vec<NoncurrentVersionTransition>,
+    #[serde(
+        rename = "NoncurrentVersionExpiration",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub noncurrent_version_expiration: Option<NoncurrentVersionExpiration>,
+    #[serde(
+        rename = "AbortIncompleteMultipartUpload",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub abort_incomplete_multipart_upload: Option<AbortIncompleteMultipartUpload>,
+}
+
+impl LifecycleRule {
+    /// 创建一条启用状态、没有任何动作的规则
+    pub fn new(id: &str, filter: LifecycleFilter) -> Self {
+        Self {
+            id: (!id.is_empty()).then(|| id.to_string()),
+            filter,
+            status: RuleStatus::Enabled,
+            transition: Vec::new(),
+            expiration: None,
+            noncurrent_version_transition: Vec::new(),
+            noncurrent_version_expiration: None,
+            abort_incomplete_multipart_upload: None,
+        }
+    }
+
+    /// 整个存储桶初始化`days`天后仍未完成的分块上传会被清理
+    /// # Examples
+    /// ```
+    /// use qcos::lifecycle::LifecycleRule;
+    /// use quick_xml::se::to_string;
+    /// let rule = LifecycleRule::abort_incomplete_uploads(7);
+    /// assert_eq!(
+    ///     to_string(&rule).unwrap(),
+    ///     "<LifecycleRule><ID>abort-incomplete-multipart-upload</ID><Filter/><Status>Enabled</Status><AbortIncompleteMultipartUpload><DaysAfterInitiation>7</DaysAfterInitiation></AbortIncompleteMultipartUpload></LifecycleRule>"
+    /// );
+    /// ```
+    pub fn abort_incomplete_uploads(days: u32) -> Self {
+        let mut rule = Self::new(
+            "abort-incomplete-multipart-upload",
+            LifecycleFilter::default(),
+        );
+        rule.abort_incomplete_multipart_upload(days);
+        rule
+    }
+
+    /// 最后修改`days`天后转换为`storage_class`
+    pub fn transition(&mut self, days: u32, storage_class: StorageClassEnum) -> &mut Self {
+        self.transition.push(Transition {
+            days: Some(days),
+            date: None,
+            storage_class,
+        });
+        self
+    }
+
+    /// 最后修改`days`天后删除
+    pub fn expiration(&mut self, days: u32) -> &mut Self {
+        self.expiration = Some(Expiration {
+            days: Some(days),
+            ..Default::default()
+        });
+        self
+    }
+
+    /// 成为历史版本`days`天后转换为`storage_class`
+    pub fn noncurrent_version_transition(
+        &mut self,
+        days: u32,
+        storage_class: StorageClassEnum,
+    ) -> &mut Self {
+        self.noncurrent_version_transition
+            .push(NoncurrentVersionTransition {
+                noncurrent_days: days,
+                storage_class,
+            });
+        self
+    }
+
+    /// 成为历史版本`days`天后删除
+    pub fn noncurrent_version_expiration(&mut self, days: u32) -> &mut Self {
+        self.noncurrent_version_expiration = Some(NoncurrentVersionExpiration {
+            noncurrent_days: days,
+        });
+        self
+    }
+
+    /// 初始化`days`天后仍未完成的分块上传会被清理
+    pub fn abort_incomplete_multipart_upload(&mut self, days: u32) -> &mut Self {
+        self.abort_incomplete_multipart_upload = Some(AbortIncompleteMultipartUpload {
+            days_after_initiation: days,
+        });
+        self
+    }
+
+    /// 是否至少包含一个动作
+    fn has_action(&self) -> bool {
+        !self.transition.is_empty()
+            || self.expiration.is_some()
+            || !self.noncurrent_version_transition.is_empty()
+            || self.noncurrent_version_expiration.is_some()
+            || self.abort_incomplete_multipart_upload.is_some()
+    }
+}
+
+/// 存储桶的生命周期配置
+/// <https://cloud.tencent.com/document/product/436/8280>
+/// ```
+/// use qcos::lifecycle::{LifecycleConfiguration, LifecycleFilter, LifecycleRule};
+/// use qcos::objects::StorageClassEnum;
+/// use quick_xml::de::from_str;
+/// use quick_xml::se::to_string;
+/// let mut rule = LifecycleRule::new("logs", LifecycleFilter::prefix("logs/"));
+/// rule.transition(30, StorageClassEnum::StandardIa)
+///     .transition(90, StorageClassEnum::ARCHIVE)
+///     .expiration(365);
+/// let config = LifecycleConfiguration { rules: vec![rule] };
+/// let s = to_string(&config).unwrap();
+/// assert_eq!(s, "<LifecycleConfiguration><Rule><ID>logs</ID><Filter><Prefix>logs/</Prefix></Filter><Status>Enabled</Status><Transition><Days>30</Days><StorageClass>STANDARD_IA</StorageClass></Transition><Transition><Days>90</Days><StorageClass>ARCHIVE</StorageClass></Transition><Expiration><Days>365</Days></Expiration></Rule></LifecycleConfiguration>");
+/// assert_eq!(from_str::<LifecycleConfiguration>(&s).unwrap(), config);
+/// ```
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Default)]
+pub struct LifecycleConfiguration {
+    #[serde(rename = "Rule", default)]
+    pub rules: Vec<LifecycleRule>,
+}
+
+impl LifecycleConfiguration {
+    /// 校验规则数量不超过上限且每条规则至少包含一个动作，不满足时返回`ErrNo::PARAM`
+    pub fn validate(&self) -> Result<(), Response> {
+        let error = |message: String| Err(Response::new(ErrNo::PARAM, message, Vec::new()));
+        if self.rules.is_empty() {
+            return error("生命周期规则不能为空".to_string());
+        }
+        if self.rules.len() > MAX_LIFECYCLE_RULES {
+            return error(format!(
+                "生命周期规则数量{}超过上限{}",
+                self.rules.len(),
+                MAX_LIFECYCLE_RULES
+            ));
+        }
+        for rule in &self.rules {
+            if !rule.has_action() {
+                return error(format!(
+                    "生命周期规则{}没有任何动作",
+                    rule.id.as_deref().unwrap_or_default()
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Client {
+    /// 设置存储桶的生命周期规则，会覆盖已有的规则
+    /// <https://cloud.tencent.com/document/product/436/8280>
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// use qcos::lifecycle::{LifecycleConfiguration, LifecycleFilter, LifecycleRule};
+    /// use qcos::objects::StorageClassEnum;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let mut rule = LifecycleRule::new("tmp", LifecycleFilter::tags("", &[("retention", "30d")]));
+    /// rule.expiration(30);
+    /// let config = LifecycleConfiguration {
+    ///     rules: vec![rule, LifecycleRule::abort_incomplete_uploads(7)],
+    /// };
+    /// let res = client.put_bucket_lifecycle(&config).await;
+    /// assert!(res.error_message.contains("403"));
+    /// };
+    /// ```
+    pub async fn put_bucket_lifecycle(&self, config: &LifecycleConfiguration) -> Response {
+        if let Err(e) = config.validate() {
+            return e;
+        }
+        self.put_bucket_config("lifecycle", config).await
+    }
+
+    /// 查询存储桶的生命周期规则，未设置时返回404
+    /// <https://cloud.tencent.com/document/product/436/8278>
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// if let Ok(config) = client.get_bucket_lifecycle().await {
+    ///     println!("{:?}", config.rules);
+    /// }
+    /// };
+    /// ```
+    pub async fn get_bucket_lifecycle(&self) -> Result<LifecycleConfiguration, Response> {
+        self.get_bucket_config("lifecycle").await
+    }
+
+    /// 删除存储桶的全部生命周期规则
+    /// <https://cloud.tencent.com/document/product/436/8284>
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let res = client.delete_bucket_lifecycle().await;
+    /// assert!(res.error_message.contains("403"));
+    /// };
+    /// ```
+    pub async fn delete_bucket_lifecycle(&self) -> Response {
+        self.delete_bucket_config("lifecycle").await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::lifecycle::{LifecycleConfiguration, LifecycleFilter, LifecycleRule, RuleStatus};
+    use crate::objects::StorageClassEnum;
+    use crate::request::ErrNo;
+
+    #[test]
+    fn test_lifecycle_configuration() {
+        let xml = r#"<LifecycleConfiguration>
+            <Rule>
+                <ID>versions</ID>
+                <Filter>
+                    <And>
+                        <Prefix>data/</Prefix>
+                        <Tag><Key>team</Key><Value>storage</Value></Tag>
+                        <Tag><Key>retention</Key><Value>30d</Value></Tag>
+                    </And>
+                </Filter>
+                <Status>Disabled</Status>
+                <NoncurrentVersionTransition>
+                    <NoncurrentDays>30</NoncurrentDays>
+                    <StorageClass>ARCHIVE</StorageClass>
+                </NoncurrentVersionTransition>
+                <NoncurrentVersionExpiration>
+                    <NoncurrentDays>180</NoncurrentDays>
+                </NoncurrentVersionExpiration>
+                <Expiration>
+                    <ExpiredObjectDeleteMarker>true</ExpiredObjectDeleteMarker>
+                </Expiration>
+            </Rule>
+            <Rule>
+                <Filter><Tag><Key>tmp</Key><Value>true</Value></Tag></Filter>
+                <Status>Enabled</Status>
+                <Expiration><Date>2030-01-01T00:00:00+08:00</Date></Expiration>
+            </Rule>
+        </LifecycleConfiguration>"#;
+        let config: LifecycleConfiguration = quick_xml::de::from_str(xml).unwrap();
+        assert!(config.validate().is_ok());
+        let rule = &config.rules[0];
+        assert_eq!(rule.status, RuleStatus::Disabled);
+        assert_eq!(
+            rule.filter,
+            LifecycleFilter::tags("data/", &[("team", "storage"), ("retention", "30d")])
+        );
+        let mut expected = LifecycleRule::new("versions", rule.filter.clone());
+        expected
+            .noncurrent_version_transition(30, StorageClassEnum::ARCHIVE)
+            .noncurrent_version_expiration(180);
+        expected.status = RuleStatus::Disabled;
+        expected.expiration = rule.expiration.clone();
+        assert_eq!(rule, &expected);
+        assert_eq!(
+            rule.expiration
+                .as_ref()
+                .unwrap()
+                .expired_object_delete_marker,
+            Some(true)
+        );
+
+        let rule = &config.rules[1];
+        assert_eq!(rule.id, None);
+        assert_eq!(rule.filter, LifecycleFilter::tags("", &[("tmp", "true")]));
+        assert_eq!(
+            rule.expiration.as_ref().unwrap().date.unwrap().to_rfc3339(),
+            "2029-12-31T16:00:00+00:00"
+        );
+
+        let config = LifecycleConfiguration {
+            rules: vec![LifecycleRule::new("empty", LifecycleFilter::default())],
+        };
+        assert_eq!(config.validate().unwrap_err().error_no, ErrNo::PARAM);
+        assert!(LifecycleConfiguration::default().validate().is_err());
+    }
+}
@@ -0,0 +1,297 @@
+//! 分块上传 见[`MultipartUpload`]
+//!
+//! 适用于需要自行调度分片的场景，例如多台机器协作上传同一个对象，或者从队列中依次取出分片上传。
+//! 初始化后只需要把`key`和`upload_id`传给其他进程，即可通过[`Client::multipart_upload`]恢复句柄继续上传。
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use reqwest::Body;
+
+use crate::acl::AclHeader;
+use crate::client::Client;
+use crate::objects::{get_part_body, StorageClassEnum};
+use crate::request::{ErrNo, Part, Response, UploadedPart};
+
+/// 完成分块上传的结果
+/// <https://cloud.tencent.com/document/product/436/7742>
+/// ```
+/// use qcos::multipart::CompleteMultipartUploadResult;
+/// use quick_xml::de::from_str;
+/// let s = r#"<CompleteMultipartUploadResult><Location>examplebucket-1250000000.cos.ap-beijing.myqcloud.com/exampleobject</Location><Bucket>examplebucket-1250000000</Bucket><Key>exampleobject</Key><ETag>"aa259a62513358f69e98e72e59856d88-3"</ETag></CompleteMultipartUploadResult>"#;
+/// let res: CompleteMultipartUploadResult = from_str(s).unwrap();
+/// assert_eq!(res.key, "exampleobject");
+/// assert_eq!(res.etag, r#""aa259a62513358f69e98e72e59856d88-3""#);
+/// ```
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct CompleteMultipartUploadResult {
+    #[serde(rename = "Location")]
+    pub location: String,
+    #[serde(rename = "Bucket")]
+    pub bucket: String,
+    #[serde(rename = "Key")]
+    pub key: String,
+    #[serde(rename = "ETag")]
+    pub etag: String,
+    /// 对象的版本ID，存储桶开启版本控制时才有值，取自响应头`x-cos-version-id`
+    #[serde(skip)]
+    pub version_id: Option<String>,
+}
+
+/// 分片号相同时保留后出现的ETag
+fn etag_map(parts: Vec<Part>) -> HashMap<u64, String> {
+    parts.into_iter().map(|x| (x.part_number, x.etag)).collect()
+}
+
+/// 分块上传句柄，对应一个UploadId
+#[derive(Debug, Clone)]
+pub struct MultipartUpload {
+    client: Client,
+    key: String,
+    upload_id: String,
+}
+
+impl Client {
+    /// 初始化分块上传，返回分块上传句柄
+    /// <https://cloud.tencent.com/document/product/436/7746>
+    /// # 参数
+    /// - key: 上传文件的key，如test/Cargo.lock
+    /// - content_type: 文件类型
+    /// - storage_class: 存储类型`StorageClassEnum` 默认STANDARD
+    /// - acl_header: 请求控制
+    ///
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// use std::path::PathBuf;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let upload = client.create_multipart_upload("Cargo.toml", Some(mime::TEXT_PLAIN_UTF_8), None, None).await.unwrap();
+    /// let file_path = PathBuf::from("Cargo.toml");
+    /// let size = std::fs::metadata(&file_path).unwrap().len();
+    /// let part = upload.upload_part_from_file(1, &file_path, 0, size).await.unwrap();
+    /// let res = upload.complete(vec![part]).await;
+    /// };
+    /// ```
+    pub async fn create_multipart_upload(
+        &self,
+        key: &str,
+        content_type: Option<mime::Mime>,
+        storage_class: Option<StorageClassEnum>,
+        acl_header: Option<AclHeader>,
+    ) -> Result<MultipartUpload, Response> {
+        let resp = self
+            .put_object_get_upload_id(key, content_type, storage_class, acl_header)
+            .await;
+        if resp.error_no != ErrNo::SUCCESS {
+            return Err(resp);
+        }
+        let upload_id = String::from_utf8_lossy(&resp.result[..]).to_string();
+        Ok(self.multipart_upload(key, &upload_id))
+    }
+
+    /// 根据已有的UploadId恢复分块上传句柄，例如在其他进程或机器上继续上传
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let upload = client.multipart_upload("Cargo.toml", "upload_id");
+    /// assert_eq!(upload.key(), "Cargo.toml");
+    /// assert_eq!(upload.upload_id(), "upload_id");
+    /// ```
+    pub fn multipart_upload(&self, key: &str, upload_id: &str) -> MultipartUpload {
+        MultipartUpload {
+            client: self.clone(),
+            key: key.to_string(),
+            upload_id: upload_id.to_string(),
+        }
+    }
+}
+
+impl MultipartUpload {
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn upload_id(&self) -> &str {
+        &self.upload_id
+    }
+
+    /// 上传一个分片，返回分片号及ETag
+    /// <https://cloud.tencent.com/document/product/436/7750>
+    /// # 参数
+    /// - part_number: 分片号，1-10000之间
+    /// - body: 分片数据
+    /// - size: 分片大小(bytes)，除最后一个分片外不能小于1MB
+    pub async fn upload_part<T: Into<Body> + Send>(
+        &self,
+        part_number: u64,
+        body: T,
+        size: u64,
+    ) -> Result<Part, Response> {
+        let resp = self
+            .client
+            .clone()
+            .put_object_part(
+                &self.key,
+                &self.upload_id,
+                part_number,
+                body,
+                size,
+                None,
+                None,
+            )
+            .await;
+        if resp.error_no != ErrNo::SUCCESS {
+            return Err(resp);
+        }
+        match resp.headers.get("etag") {
+            Some(etag) => Ok(Part {
+                part_number,
+                etag: etag.clone(),
+            }),
+            None => Err(Response::new(
+                ErrNo::OTHER,
+                format!("分片{}未返回ETag", part_number),
+                Default::default(),
+            )),
+        }
+    }
+
+    /// 从本地文件的`offset`处读取`size`字节作为一个分片上传，数据以流的形式读取
+    /// # 参数
+    /// - part_number: 分片号，1-10000之间
+    /// - file_path: 文件路径
+    /// - offset: 分片在文件中的起始位置
+    /// - size: 分片大小(bytes)
+    pub async fn upload_part_from_file(
+        &self,
+        part_number: u64,
+        file_path: &PathBuf,
+        offset: u64,
+        size: u64,
+    ) -> Result<Part, Response> {
+        let body = match get_part_body(file_path, offset, size).await {
+            Ok(body) => body,
+            Err(e) => {
+                return Err(Response::new(
+                    ErrNo::IO,
+                    format!("读取文件失败: {:?}, {}", file_path, e),
+                    Default::default(),
+                ))
+            }
+        };
+        self.upload_part(part_number, body, size).await
+    }
+
+    /// 查询已经上传的全部分片
+    /// <https://cloud.tencent.com/document/product/436/7747>
+    pub async fn list_parts(&self) -> Result<Vec<UploadedPart>, Response> {
+        let mut parts = Vec::new();
+        let mut marker = 0;
+        loop {
+            let result = self
+                .client
+                .list_parts(&self.key, &self.upload_id, marker, 1000)
+                .await?;
+            parts.extend(result.part);
+            if !result.is_truncated {
+                break;
+            }
+            marker = result.next_part_number_marker;
+        }
+        Ok(parts)
+    }
+
+    /// 完成分块上传，`parts`无需排序
+    /// <https://cloud.tencent.com/document/product/436/7742>
+    pub async fn complete(
+        &self,
+        parts: Vec<Part>,
+    ) -> Result<CompleteMultipartUploadResult, Response> {
+        let resp = self
+            .client
+            .put_object_complete_part(&self.key, etag_map(parts), &self.upload_id)
+            .await;
+        let version_id = resp.version_id().map(String::from);
+        let mut result: CompleteMultipartUploadResult = self.client.make_xml_response(Ok(resp))?;
+        result.version_id = version_id;
+        Ok(result)
+    }
+
+    /// 终止分块上传，清理已上传的分片
+    /// <https://cloud.tencent.com/document/product/436/7740>
+    pub async fn abort(&self) -> Result<(), Response> {
+        let resp = self
+            .client
+            .abort_object_part(&self.key, &self.upload_id)
+            .await;
+        if resp.error_no != ErrNo::SUCCESS {
+            return Err(resp);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::multipart::{etag_map, CompleteMultipartUploadResult};
+    use crate::objects::sorted_parts;
+    use crate::request::{CompleteMultipartUpload, Part};
+
+    #[test]
+    fn test_complete_multipart_upload_result() {
+        // 缺少必需字段或响应体是错误信息时解析失败，不会得到字段为空的结果
+        for xml in [
+            r#"<CompleteMultipartUploadResult><Bucket>b</Bucket><Key>k</Key><ETag>e</ETag></CompleteMultipartUploadResult>"#,
+            r#"<CompleteMultipartUploadResult><Location>l</Location><Bucket>b</Bucket><Key>k</Key></CompleteMultipartUploadResult>"#,
+            r#"<Error><Code>InvalidPart</Code><Message>One or more of the specified parts could not be found</Message></Error>"#,
+        ] {
+            assert!(
+                quick_xml::de::from_str::<CompleteMultipartUploadResult>(xml).is_err(),
+                "{}",
+                xml
+            );
+        }
+
+        // 版本ID来自响应头，即使响应体中出现也不解析、不序列化
+        let xml = r#"<CompleteMultipartUploadResult><Location>l</Location><Bucket>b</Bucket><Key>k</Key><ETag>e</ETag><VersionId>MTg0NDUxNTc1NjIzMTQ1MDAwODg</VersionId></CompleteMultipartUploadResult>"#;
+        let mut res: CompleteMultipartUploadResult = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(res.version_id, None);
+        res.version_id = Some("MTg0NDUxNTc1NjIzMTQ1MDAwODg".to_string());
+        assert!(!quick_xml::se::to_string(&res)
+            .unwrap()
+            .contains("MTg0NDUxNTc1NjIzMTQ1MDAwODg"));
+    }
+
+    #[test]
+    fn test_complete_parts() {
+        let part = |part_number: u64, etag: &str| Part {
+            part_number,
+            etag: etag.to_string(),
+        };
+        let parts = vec![
+            part(3, "\"c\""),
+            part(1, "\"a\""),
+            part(10, "\"d\""),
+            part(2, "\"b-old\""),
+            part(2, "\"b\""),
+        ];
+        let sorted = sorted_parts(&etag_map(parts));
+        assert_eq!(
+            sorted,
+            vec![
+                part(1, "\"a\""),
+                part(2, "\"b\""),
+                part(3, "\"c\""),
+                part(10, "\"d\""),
+            ]
+        );
+        let s = quick_xml::se::to_string(&CompleteMultipartUpload { part: sorted }).unwrap();
+        assert!(s.starts_with(
+            r#"<CompleteMultipartUpload><Part><PartNumber>1</PartNumber><ETag>"a"</ETag></Part>"#
+        ));
+        assert!(s.ends_with(
+            r#"<Part><PartNumber>10</PartNumber><ETag>"d"</ETag></Part></CompleteMultipartUpload>"#
+        ));
+    }
+}
@@ -2,10 +2,15 @@
 #![allow(clippy::too_many_arguments)]
 
 use crate::acl;
+use crate::checksum::{checksum_reader, content_md5, crc64, crc64_combine, verify_crc64, Crc64};
 use crate::client;
+use crate::options::ObjectOptions;
 pub use crate::request::{
-    CompleteMultipartUpload, ErrNo, InitiateMultipartUploadResult, Part, Request, Response,
+    CompleteMultipartUpload, CopyObjectResult, Delete, DeleteResult, ErrNo,
+    InitiateMultipartUploadResult, ListMultipartUploadsResult, ListPartsResult, ObjectIdentifier,
+    Part, Request, Response, Upload, UploadedPart,
 };
+use chrono::{DateTime, Utc};
 #[cfg(feature = "progress-bar")]
 use futures_util::TryStreamExt;
 #[cfg(feature = "progress-bar")]
@@ -13,31 +18,68 @@ pub use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
 pub use mime;
 pub use quick_xml::de::from_str;
 pub use quick_xml::se::to_string;
-use reqwest::header::{HeaderName, HeaderValue, RANGE};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, RANGE};
 use reqwest::header::{CONTENT_LENGTH, CONTENT_TYPE};
 pub use reqwest::Body;
-use std::io::Cursor;
-use std::{collections::HashMap, path::PathBuf};
+use std::collections::HashMap;
+use std::io::{Cursor, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
 use tokio::fs;
-use tokio::io::{self, AsyncReadExt, AsyncWriteExt};
-#[cfg(feature = "progress-bar")]
+use tokio::io::{self, AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
+use tokio::task::JoinSet;
 use tokio_util::io::ReaderStream;
 
+use std::convert::Infallible;
+use std::fmt::Display;
 use std::str::FromStr;
 #[cfg(feature = "progress-bar")]
 use tokio::io::BufReader;
 
+/// 批量删除单次最多1000个对象
+pub const MAX_DELETE_OBJECTS: usize = 1000;
+
 // 最小上传分片大小 1MB
 const PART_MIN_SIZE: u64 = 1024 * 1024;
 
 // 最大上传分片大小1GB
 const PART_MAX_SIZE: u64 = 1024 * 1024 * 1024;
 
+// 默认上传分片大小约50MB
+const PART_DEFAULT_SIZE: u64 = PART_MAX_SIZE / 10 / 2;
+
+// 最大分片数量
+const PART_MAX_NUM: u64 = 10000;
+
+// 默认最大上传线程数
+pub(crate) const DEFAULT_THREADS: u64 = 20;
+
+// 最大上传线程数上限
+const MAX_THREADS: u64 = 1000;
+
+// 自适应模式初始分片大小 8MB
+const ADAPTIVE_INITIAL_PART_SIZE: u64 = 8 * 1024 * 1024;
+
+// 自适应模式初始并发数
+const ADAPTIVE_INITIAL_THREADS: usize = 4;
+
+// 自适应模式下单个分片期望的上传耗时(秒)
+const ADAPTIVE_PART_SECS: f64 = 5.0;
+
 // 为了兼容以前的版本
 pub struct Objects;
 
 /// 存储类型
 /// <https://cloud.tencent.com/document/product/436/33417>
+/// # Examples
+/// ```
+/// use qcos::objects::StorageClassEnum;
+/// assert_eq!("STANDARD_IA".parse::<StorageClassEnum>().unwrap(), StorageClassEnum::StandardIa);
+/// assert_eq!(StorageClassEnum::DeepArchive.to_string(), "DEEP_ARCHIVE");
+/// assert_eq!(StorageClassEnum::default(), StorageClassEnum::STANDARD);
+/// assert_eq!("FOO".parse::<StorageClassEnum>().unwrap(), StorageClassEnum::Unknown("FOO".to_string()));
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Hash, Default)]
 pub enum StorageClassEnum {
     MazStandard,
     MazStandardIa,
@@ -46,21 +88,493 @@ pub enum StorageClassEnum {
     StandardIa,
     ARCHIVE,
     DeepArchive,
+    #[default]
     STANDARD,
+    /// 未知的取值，保留原始字符串
+    Unknown(String),
+}
+
+impl Display for StorageClassEnum {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            StorageClassEnum::ARCHIVE => "ARCHIVE",
+            StorageClassEnum::STANDARD => "STANDARD",
+            StorageClassEnum::StandardIa => "STANDARD_IA",
+            StorageClassEnum::MazStandard => "MAZ_STANDARD",
+            StorageClassEnum::DeepArchive => "DEEP_ARCHIVE",
+            StorageClassEnum::MazStandardIa => "MAZ_STANDARD_IA",
+            StorageClassEnum::IntelligentTiering => "INTELLIGENT_TIERING",
+            StorageClassEnum::MazIntelligentTiering => "MAZ_INTELLIGENT_TIERING",
+            StorageClassEnum::Unknown(s) => s,
+        };
+        f.write_str(s)
+    }
+}
+
+impl FromStr for StorageClassEnum {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "ARCHIVE" => StorageClassEnum::ARCHIVE,
+            "STANDARD" => StorageClassEnum::STANDARD,
+            "STANDARD_IA" => StorageClassEnum::StandardIa,
+            "MAZ_STANDARD" => StorageClassEnum::MazStandard,
+            "DEEP_ARCHIVE" => StorageClassEnum::DeepArchive,
+            "MAZ_STANDARD_IA" => StorageClassEnum::MazStandardIa,
+            "INTELLIGENT_TIERING" => StorageClassEnum::IntelligentTiering,
+            "MAZ_INTELLIGENT_TIERING" => StorageClassEnum::MazIntelligentTiering,
+            _ => StorageClassEnum::Unknown(s.to_string()),
+        })
+    }
 }
 
 impl From<StorageClassEnum> for String {
     fn from(value: StorageClassEnum) -> Self {
-        match value {
-            StorageClassEnum::ARCHIVE => String::from("ARCHIVE"),
-            StorageClassEnum::STANDARD => String::from("STANDARD"),
-            StorageClassEnum::StandardIa => String::from("STANDARD_IA"),
-            StorageClassEnum::MazStandard => String::from("MAZ_STANDARD"),
-            StorageClassEnum::DeepArchive => String::from("DEEP_ARCHIVE"),
-            StorageClassEnum::MazStandardIa => String::from("MAZ_STANDARD_IA"),
-            StorageClassEnum::IntelligentTiering => String::from("INTELLIGENT_TIERING"),
-            StorageClassEnum::MazIntelligentTiering => String::from("MAZ_INTELLIGENT_TIERING"),
+        value.to_string()
+    }
+}
+
+impl serde::Serialize for StorageClassEnum {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for StorageClassEnum {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        // 未知的取值解析为`Unknown`，不会失败
+        Ok(s.parse().unwrap())
+    }
+}
+
+impl StorageClassEnum {
+    /// 是否为归档存储类型，需要恢复后才能读取
+    pub fn is_archive(&self) -> bool {
+        matches!(
+            self,
+            StorageClassEnum::ARCHIVE | StorageClassEnum::DeepArchive
+        )
+    }
+
+    /// 从响应头`x-cos-storage-class`中解析存储类型，未返回时为STANDARD
+    /// # Examples
+    /// ```
+    /// use qcos::objects::StorageClassEnum;
+    /// use std::collections::HashMap;
+    /// let mut headers = HashMap::new();
+    /// assert_eq!(StorageClassEnum::from_headers(&headers), StorageClassEnum::STANDARD);
+    /// headers.insert("x-cos-storage-class".to_string(), "ARCHIVE".to_string());
+    /// assert!(StorageClassEnum::from_headers(&headers).is_archive());
+    /// ```
+    pub fn from_headers(headers: &HashMap<String, String>) -> Self {
+        headers
+            .get("x-cos-storage-class")
+            .map(|x| x.parse().unwrap())
+            .unwrap_or_default()
+    }
+}
+
+/// 打开文件并定位到`offset`，返回只能读取`size`字节的reader
+async fn open_part(file_path: &PathBuf, offset: u64, size: u64) -> io::Result<io::Take<fs::File>> {
+    let mut file = fs::File::open(file_path).await?;
+    file.seek(SeekFrom::Start(offset)).await?;
+    Ok(file.take(size))
+}
+
+/// 读取文件的一个分片，计算`Content-MD5`及CRC64
+pub(crate) async fn checksum_part(
+    file_path: &PathBuf,
+    offset: u64,
+    size: u64,
+) -> io::Result<(String, u64)> {
+    checksum_reader(open_part(file_path, offset, size).await?).await
+}
+
+/// 以流的形式读取文件的一个分片作为请求体，分片数据不会整体读入内存
+pub(crate) async fn get_part_body(file_path: &PathBuf, offset: u64, size: u64) -> io::Result<Body> {
+    let reader = open_part(file_path, offset, size).await?;
+    Ok(Body::wrap_stream(ReaderStream::new(reader)))
+}
+
+/// 同[`get_part_body`]，读取的同时更新进度条
+#[cfg(feature = "progress-bar")]
+async fn get_part_body_progress_bar(
+    file_path: &PathBuf,
+    offset: u64,
+    size: u64,
+    pb: ProgressBar,
+) -> io::Result<Body> {
+    let reader = open_part(file_path, offset, size).await?;
+    let stream = ReaderStream::new(reader).inspect_ok(move |chunk| {
+        pb.inc(chunk.len() as u64);
+    });
+    Ok(Body::wrap_stream(stream))
+}
+
+/// 分片上传任务的结果
+struct PartOutcome {
+    part_number: u64,
+    part_size: u64,
+    // 开启完整性校验时分片数据的CRC64
+    crc64: Option<u64>,
+    response: Response,
+}
+
+/// 等待任意一个分片上传任务完成，成功时按分片号记录ETag，失败时返回对应的`Response`
+async fn join_next_part(
+    tasks: &mut JoinSet<PartOutcome>,
+    etag_map: &mut HashMap<u64, String>,
+) -> Result<PartOutcome, Response> {
+    match tasks.join_next().await {
+        Some(Ok(outcome)) => {
+            if outcome.response.error_no != ErrNo::SUCCESS {
+                return Err(outcome.response);
+            }
+            match outcome.response.headers.get("etag") {
+                Some(etag) => {
+                    etag_map.insert(outcome.part_number, etag.clone());
+                    Ok(outcome)
+                }
+                None => Err(Response::new(
+                    ErrNo::OTHER,
+                    format!("分片{}未返回ETag", outcome.part_number),
+                    Default::default(),
+                )),
+            }
+        }
+        Some(Err(e)) => Err(Response::new(
+            ErrNo::OTHER,
+            format!("分片上传任务异常: {}", e),
+            Default::default(),
+        )),
+        None => Err(Response::new(
+            ErrNo::OTHER,
+            "没有进行中的分片上传任务".to_string(),
+            Default::default(),
+        )),
+    }
+}
+
+/// 按顺序校验分段下载的结果：每段数据长度需与请求的range一致，
+/// 开启完整性校验时还会计算整个对象的CRC64并与响应头比较
+struct RangeVerifier {
+    size: usize,
+    threads: usize,
+    part_size: usize,
+    index: usize,
+    crc: Option<Crc64>,
+    headers: HashMap<String, String>,
+}
+
+impl RangeVerifier {
+    fn new(size: usize, threads: usize, integrity_check: bool) -> Self {
+        Self {
+            size,
+            threads,
+            part_size: size / threads,
+            index: 0,
+            crc: integrity_check.then(Crc64::new),
+            headers: HashMap::new(),
+        }
+    }
+
+    /// 校验下一段数据
+    fn verify(&mut self, response: &Response) -> Result<(), Response> {
+        if response.error_no != ErrNo::SUCCESS {
+            return Err(response.clone());
+        }
+        // 最后一段包含剩余的全部数据
+        let expected = if self.index == self.threads - 1 {
+            self.size - (self.threads - 1) * self.part_size
+        } else {
+            self.part_size
+        };
+        if response.result.len() != expected {
+            return Err(Response::new(
+                ErrNo::INTEGRITY,
+                format!(
+                    "第{}段数据长度不一致, 期望: {}, 实际: {}",
+                    self.index + 1,
+                    expected,
+                    response.result.len()
+                ),
+                Default::default(),
+            ));
+        }
+        if let Some(crc) = self.crc.as_mut() {
+            crc.update(&response.result);
+            if self.index == 0 {
+                self.headers = response.headers.clone();
+            }
+        }
+        self.index += 1;
+        Ok(())
+    }
+
+    /// 全部数据校验完成后比较CRC64
+    fn finish(&self) -> Result<(), Response> {
+        match &self.crc {
+            Some(crc) => verify_crc64(&self.headers, crc.value()),
+            None => Ok(()),
+        }
+    }
+}
+
+/// 读取分段下载的响应，同时更新进度条
+#[cfg(feature = "progress-bar")]
+async fn read_range_progress_bar(mut resp: reqwest::Response, pb: &ProgressBar) -> Response {
+    let status_code = resp.status();
+    let mut headers = HashMap::new();
+    for (k, v) in resp.headers() {
+        headers.insert(k.to_string(), String::from_utf8_lossy(v.as_bytes()).into());
+    }
+    let mut data = Vec::new();
+    loop {
+        match resp.chunk().await {
+            Ok(Some(chunk)) => {
+                pb.inc(chunk.len() as u64);
+                data.push(chunk);
+            }
+            Ok(None) => break,
+            Err(e) => return e.into(),
+        }
+    }
+    let mut response = Response::data_success(data.concat());
+    response.headers = headers;
+    response.status = Some(status_code.as_u16());
+    response.error_no = ErrNo::from_status(status_code);
+    if response.error_no != ErrNo::SUCCESS {
+        response.error_message = status_code.to_string();
+    }
+    response
+}
+
+/// 根据HEAD请求返回的headers生成分段下载的请求头，带上ETag作为`If-Match`
+fn download_headers(head: &HashMap<String, String>) -> Result<HeaderMap, Response> {
+    let mut pinned = ObjectOptions::new();
+    if let Some(etag) = head.get("etag") {
+        pinned.insert_if_match(etag)?;
+    }
+    let mut headers = HeaderMap::new();
+    pinned.extend_headers(&mut headers);
+    Ok(headers)
+}
+
+/// 将分片号及ETag转为按part_number排序的分片列表，用于完成分块上传
+pub(crate) fn sorted_parts(etag_map: &HashMap<u64, String>) -> Vec<Part> {
+    let mut parts: Vec<Part> = etag_map
+        .iter()
+        .map(|(k, v)| Part {
+            part_number: *k,
+            etag: v.to_string(),
+        })
+        .collect();
+    parts.sort_by_key(|x| x.part_number);
+    parts
+}
+
+/// 获取本地文件大小
+async fn get_file_size(file_path: &PathBuf) -> Result<u64, Response> {
+    match fs::metadata(file_path).await {
+        Ok(meta) => Ok(meta.len()),
+        Err(e) => Err(Response::new(
+            ErrNo::IO,
+            format!("获取文件大小失败: {:?}, {}", file_path, e),
+            Default::default(),
+        )),
+    }
+}
+
+/// 分片上传进度条样式，未开启`progress-bar` feature时不会有值
+#[cfg(feature = "progress-bar")]
+type PartProgress = ProgressStyle;
+#[cfg(not(feature = "progress-bar"))]
+type PartProgress = std::convert::Infallible;
+
+#[cfg(feature = "progress-bar")]
+fn get_progress_style(progress_style: Option<ProgressStyle>) -> ProgressStyle {
+    match progress_style {
+        Some(sty) => sty,
+        None => ProgressStyle::default_bar()
+            .template("{spinner:.green} [{elapsed_precise}] [{wide_bar:.cyan/blue}] {bytes}/{total_bytes} ({bytes_per_sec}, {eta})")
+            .unwrap()
+            .progress_chars("#>-"),
+    }
+}
+
+/// 剩余`remain_bytes`字节在`remain_parts`个分片内上传完所需的最小分片大小，按1MB向上取整
+fn min_part_size(remain_bytes: u64, remain_parts: u64) -> u64 {
+    let size = remain_bytes.div_ceil(remain_parts.max(1));
+    size.div_ceil(PART_MIN_SIZE).max(1) * PART_MIN_SIZE
+}
+
+/// 根据文件大小确定分片大小，保证分片数量不超过10000
+///
+/// 未指定`part_size`时默认约50MB，文件较大时自动增大；指定的`part_size`需在1MB-1GB之间，
+/// 且不能导致分片数量超过10000
+pub(crate) fn get_part_size(file_size: u64, part_size: Option<u64>) -> Result<u64, Response> {
+    let min_size = min_part_size(file_size, PART_MAX_NUM);
+    if min_size > PART_MAX_SIZE {
+        return Err(Response::new(
+            ErrNo::PARAM,
+            format!(
+                "文件大小{}超过分块上传的上限{}",
+                file_size,
+                PART_MAX_SIZE * PART_MAX_NUM
+            ),
+            Default::default(),
+        ));
+    }
+    match part_size {
+        Some(part_size) if !(PART_MIN_SIZE..=PART_MAX_SIZE).contains(&part_size) => {
+            Err(Response::new(
+                ErrNo::PARAM,
+                format!("分片大小{}不在1MB-1GB之间", part_size),
+                Default::default(),
+            ))
+        }
+        Some(part_size) if part_size < min_size => Err(Response::new(
+            ErrNo::PARAM,
+            format!(
+                "分片大小{}过小，分片数量将超过{}，至少需要{}",
+                part_size, PART_MAX_NUM, min_size
+            ),
+            Default::default(),
+        )),
+        Some(part_size) => Ok(part_size),
+        None => Ok(PART_DEFAULT_SIZE.max(min_size)),
+    }
+}
+
+/// 校验最大上传线程数，默认20
+pub(crate) fn get_max_threads(max_threads: Option<u64>) -> Result<usize, Response> {
+    let max_threads = max_threads.unwrap_or(DEFAULT_THREADS);
+    if !(1..=MAX_THREADS).contains(&max_threads) {
+        return Err(Response::new(
+            ErrNo::PARAM,
+            format!("最大上传线程数{}不在1-{}之间", max_threads, MAX_THREADS),
+            Default::default(),
+        ));
+    }
+    Ok(max_threads as usize)
+}
+
+/// 分块上传的分片大小及并发数规划，自适应模式下根据实测吞吐量调整
+struct PartPlanner {
+    file_size: u64,
+    part_size: u64,
+    threads: usize,
+    max_threads: usize,
+    adaptive: bool,
+    // 以下字段仅在自适应模式下使用
+    window_start: Instant,
+    window_bytes: u64,
+    window_parts: usize,
+    last_throughput: f64,
+    // 并发数调整方向，1为增加，-1为减少
+    direction: i8,
+}
+
+impl PartPlanner {
+    /// 固定分片大小及并发数
+    fn new(
+        file_size: u64,
+        part_size: Option<u64>,
+        max_threads: Option<u64>,
+    ) -> Result<Self, Response> {
+        let part_size = get_part_size(file_size, part_size)?;
+        let max_threads = get_max_threads(max_threads)?;
+        Ok(Self {
+            file_size,
+            part_size,
+            threads: max_threads,
+            max_threads,
+            adaptive: false,
+            window_start: Instant::now(),
+            window_bytes: 0,
+            window_parts: 0,
+            last_throughput: 0.0,
+            direction: 1,
+        })
+    }
+
+    /// 自适应模式，从较小的分片及并发数开始
+    fn adaptive(file_size: u64, max_threads: Option<u64>) -> Result<Self, Response> {
+        // 校验文件大小是否超过分块上传的上限
+        get_part_size(file_size, None)?;
+        let max_threads = get_max_threads(max_threads)?;
+        Ok(Self {
+            file_size,
+            part_size: ADAPTIVE_INITIAL_PART_SIZE.max(min_part_size(file_size, PART_MAX_NUM)),
+            threads: ADAPTIVE_INITIAL_THREADS.min(max_threads),
+            max_threads,
+            adaptive: true,
+            window_start: Instant::now(),
+            window_bytes: 0,
+            window_parts: 0,
+            last_throughput: 0.0,
+            direction: 1,
+        })
+    }
+
+    /// 当前允许的并发数
+    fn threads(&self) -> usize {
+        self.threads
+    }
+
+    /// 已上传`uploaded`字节时，分片号为`part_number`的分片大小
+    fn next_part_size(&self, uploaded: u64, part_number: u64) -> u64 {
+        let last_bytes = self.file_size - uploaded;
+        let mut part_size = self.part_size;
+        if self.adaptive {
+            // 保证剩余数据在分片数量限制内能够上传完
+            part_size = part_size
+                .max(min_part_size(last_bytes, PART_MAX_NUM + 1 - part_number))
+                .min(PART_MAX_SIZE);
+        }
+        // 倒数第二次上传后剩余小于1M，附加到倒数第二次上传
+        if last_bytes < part_size + PART_MIN_SIZE && last_bytes < PART_MAX_SIZE {
+            return last_bytes;
         }
+        part_size
+    }
+
+    /// 记录一个分片上传完成，自适应模式下每完成一轮分片根据吞吐量调整分片大小及并发数
+    fn record(&mut self, part_size: u64) {
+        if !self.adaptive {
+            return;
+        }
+        self.window_bytes += part_size;
+        self.window_parts += 1;
+        if self.window_parts < self.threads {
+            return;
+        }
+        let secs = self.window_start.elapsed().as_secs_f64();
+        if secs <= 0.0 {
+            return;
+        }
+        let throughput = self.window_bytes as f64 / secs;
+        // 按单个连接的吞吐量调整分片大小
+        let size = (throughput / self.threads as f64 * ADAPTIVE_PART_SECS) as u64;
+        // 按1MB四舍五入
+        let size = (size + PART_MIN_SIZE / 2) / PART_MIN_SIZE * PART_MIN_SIZE;
+        self.part_size = size.clamp(PART_MIN_SIZE, PART_MAX_SIZE);
+        // 吞吐量下降时反向调整并发数
+        if throughput < self.last_throughput {
+            self.direction = -self.direction;
+        }
+        let step = (self.threads / 4).max(1);
+        self.threads = if self.direction > 0 {
+            (self.threads + step).min(self.max_threads)
+        } else {
+            self.threads.saturating_sub(step).max(1)
+        };
+        self.last_throughput = throughput;
+        self.window_start = Instant::now();
+        self.window_bytes = 0;
+        self.window_parts = 0;
     }
 }
 
@@ -93,6 +607,26 @@ impl client::Client {
         key: &str,
         content_type: Option<mime::Mime>,
         acl_header: Option<acl::AclHeader>,
+    ) -> Response {
+        self.put_object_with_options(
+            file_path,
+            key,
+            content_type,
+            acl_header,
+            ObjectOptions::new(),
+        )
+        .await
+    }
+
+    /// 同[`put_object`](Self::put_object)，可传入条件请求头、版本ID等可选项
+    /// - options: 条件请求头等可选项，如`If-Match`可实现仅当对象未被他人修改时才覆盖
+    pub async fn put_object_with_options(
+        &self,
+        file_path: &PathBuf,
+        key: &str,
+        content_type: Option<mime::Mime>,
+        acl_header: Option<acl::AclHeader>,
+        options: ObjectOptions,
     ) -> Response {
         let buf = match tokio::fs::read(file_path).await {
             Ok(file) => file,
@@ -104,7 +638,7 @@ impl client::Client {
                 )
             }
         };
-        self.put_object_binary(buf, key, content_type, acl_header)
+        self.put_object_binary_with_options(buf, key, content_type, acl_header, options)
             .await
     }
 
@@ -138,6 +672,29 @@ impl client::Client {
         content_type: Option<mime::Mime>,
         acl_header: Option<acl::AclHeader>,
         progress_style: Option<ProgressStyle>,
+    ) -> Response {
+        self.put_object_progress_bar_with_options(
+            file_path,
+            key,
+            content_type,
+            acl_header,
+            ObjectOptions::new(),
+            progress_style,
+        )
+        .await
+    }
+
+    /// 同[`put_object_progress_bar`](Self::put_object_progress_bar)，可传入条件请求头、版本ID等可选项
+    /// - options: 条件请求头等可选项
+    #[cfg(feature = "progress-bar")]
+    pub async fn put_object_progress_bar_with_options(
+        &self,
+        file_path: &PathBuf,
+        key: &str,
+        content_type: Option<mime::Mime>,
+        acl_header: Option<acl::AclHeader>,
+        options: ObjectOptions,
+        progress_style: Option<ProgressStyle>,
     ) -> Response {
         let file = match tokio::fs::File::open(file_path).await {
             Ok(file) => file,
@@ -150,12 +707,13 @@ impl client::Client {
             }
         };
         let file_size = file.metadata().await.unwrap().len();
-        self.put_object_binary_progress_bar(
+        self.put_object_binary_progress_bar_with_options(
             file,
             key,
             file_size,
             content_type,
             acl_header,
+            options,
             progress_style,
         )
         .await
@@ -169,8 +727,8 @@ impl client::Client {
     /// - content_type: 文件类型
     /// - storage_class: 存储类型`StorageClassEnum` 默认STANDARD
     /// - acl_header: 请求控制
-    /// - part_size: 分片大小，单位bytes，要求1M-1G之间，默认50M
-    /// - max_threads: 最大上传线程数，默认20， 每个线程会尝试10次
+    /// - part_size: 分片大小，单位bytes，要求1M-1G之间，默认50M，文件较大时自动增大以保证分片数量不超过10000
+    /// - max_threads: 最大上传线程数，要求1-1000之间，默认20， 每个线程会尝试10次
     /// - progress_style: 进度条样式
     ///
     /// # Examples
@@ -200,137 +758,24 @@ impl client::Client {
         max_threads: Option<u64>,
         progress_style: Option<ProgressStyle>,
     ) -> Response {
-        let part_size = part_size.unwrap_or(PART_MAX_SIZE / 10 / 2);
-        assert!((PART_MIN_SIZE..PART_MAX_SIZE).contains(&part_size));
-        assert!(max_threads.unwrap_or(20) <= 1000);
-        let mut file = match tokio::fs::File::open(file_path).await {
-            Ok(file) => file,
-            Err(e) => {
-                return Response::new(
-                    ErrNo::IO,
-                    format!("打开文件失败: {:?}, {}", file_path, e),
-                    Default::default(),
-                )
-            }
+        let file_size = match get_file_size(file_path).await {
+            Ok(size) => size,
+            Err(e) => return e,
         };
-        let file_size = match file.metadata().await {
-            Ok(meta) => meta.len(),
-            Err(e) => {
-                return Response::new(
-                    ErrNo::IO,
-                    format!("获取文件大小失败: {:?}, {}", file_path, e),
-                    Default::default(),
-                )
-            }
+        let planner = match PartPlanner::new(file_size, part_size, max_threads) {
+            Ok(planner) => planner,
+            Err(e) => return e,
         };
-        let mut part_number = 1;
-        let mut etag_map = HashMap::new();
-        let upload_id_response = self
-            .put_object_get_upload_id(key, content_type.clone(), storage_class, acl_header.clone())
-            .await;
-        if upload_id_response.error_no != ErrNo::SUCCESS {
-            return upload_id_response;
-        }
-        let upload_id = String::from_utf8_lossy(&upload_id_response.result[..]).to_string();
-        // 默认20个线程
-        let max_threads = max_threads.unwrap_or(20);
-        let mut tasks = Vec::new();
-        let mut upload_bytes = 0;
-        let mut part_number1 = 1;
-        let multi = MultiProgress::new();
-        let sty = match progress_style{
-            Some(sty)=>sty,
-            None=> ProgressStyle::default_bar().template("{spinner:.green} [{elapsed_precise}] [{wide_bar:.cyan/blue}] {bytes}/{total_bytes} ({bytes_per_sec}, {eta})").unwrap().progress_chars("#>-")};
-        loop {
-            if upload_bytes >= file_size {
-                break;
-            }
-            let mut part_size1 = part_size;
-            let last_bytes = file_size - upload_bytes;
-            // 倒数第二次上传后剩余小于1M，附加到倒数第二次上传
-            if last_bytes < part_size + PART_MIN_SIZE && last_bytes < PART_MAX_SIZE {
-                part_size1 = last_bytes;
-            }
-            let mut body: Vec<u8> = vec![0; part_size1 as usize];
-            if let Err(e) = file.read_exact(&mut body).await {
-                // 调用清理
-                self.abort_object_part(key, &upload_id).await;
-                return Response::new(
-                    ErrNo::IO,
-                    format!("读取文件失败: {:?}, {}", file_path, e),
-                    Default::default(),
-                );
-            }
-            upload_bytes += part_size1;
-            if tasks.len() < max_threads as usize {
-                let key = key.to_string();
-                let upload_id = upload_id.clone();
-                let this = self.clone();
-                let acl_header = acl_header.clone();
-                let content_type = content_type.clone();
-                let pb = multi.add(ProgressBar::new(body.len() as u64));
-                pb.set_style(sty.clone());
-                let handle = tokio::spawn(async move {
-                    let mut resp = Response::default();
-                    let mut try_times = 10;
-                    while try_times > 0 {
-                        try_times -= 1;
-                        resp = this
-                            .clone()
-                            .put_object_part_progress_bar(
-                                &key,
-                                &upload_id,
-                                part_number,
-                                body.clone(),
-                                content_type.clone(),
-                                acl_header.clone(),
-                                pb.clone(),
-                            )
-                            .await;
-                        if resp.error_no != ErrNo::SUCCESS {
-                            if try_times == 0 {
-                                // 调用清理
-                                this.abort_object_part(&key, upload_id.as_str()).await;
-                            }
-                        } else {
-                            break;
-                        }
-                    }
-                    resp
-                });
-                tasks.push(handle);
-                part_number += 1;
-            } else {
-                for task in tasks {
-                    let response = task.await.unwrap();
-                    if response.error_no != ErrNo::SUCCESS {
-                        return response;
-                    }
-                    etag_map.insert(part_number1, response.headers["etag"].clone());
-                    part_number1 += 1;
-                }
-                tasks = Vec::new();
-            }
-        }
-        if !tasks.is_empty() {
-            for task in tasks {
-                let response = task.await.unwrap();
-                if response.error_no != ErrNo::SUCCESS {
-                    return response;
-                }
-                etag_map.insert(part_number1, response.headers["etag"].clone());
-                part_number1 += 1;
-            }
-        }
-        // 调用合并
-        let resp = self
-            .put_object_complete_part(key, etag_map, upload_id.as_str())
-            .await;
-        if resp.error_no != ErrNo::SUCCESS {
-            // 调用清理
-            self.abort_object_part(key, upload_id.as_str()).await;
-        }
-        resp
+        self.upload_big_object(
+            file_path,
+            key,
+            content_type,
+            storage_class,
+            acl_header,
+            planner,
+            Some(get_progress_style(progress_style)),
+        )
+        .await
     }
 
     /// 上传本地大文件，无进度条
@@ -341,8 +786,8 @@ impl client::Client {
     /// - content_type: 文件类型
     /// - storage_class: 存储类型`StorageClassEnum` 默认STANDARD
     /// - acl_header: 请求控制
-    /// - part_size: 分片大小，单位bytes，要求1M-1G之间，默认50M
-    /// - max_threads: 最大上传线程数，默认20， 每个线程会尝试10次
+    /// - part_size: 分片大小，单位bytes，要求1M-1G之间，默认50M，文件较大时自动增大以保证分片数量不超过10000
+    /// - max_threads: 最大上传线程数，要求1-1000之间，默认20， 每个线程会尝试10次
     ///
     /// # Examples
     /// ```
@@ -369,121 +814,271 @@ impl client::Client {
         part_size: Option<u64>,
         max_threads: Option<u64>,
     ) -> Response {
-        let part_size = part_size.unwrap_or(PART_MAX_SIZE / 10 / 2);
-        assert!((PART_MIN_SIZE..PART_MAX_SIZE).contains(&part_size));
-        assert!(max_threads.unwrap_or(20) <= 1000);
-        let mut file = match tokio::fs::File::open(file_path).await {
-            Ok(file) => file,
-            Err(e) => {
-                return Response::new(
-                    ErrNo::IO,
-                    format!("打开文件失败: {:?}, {}", file_path, e),
-                    Default::default(),
-                )
-            }
+        let file_size = match get_file_size(file_path).await {
+            Ok(size) => size,
+            Err(e) => return e,
         };
-        let file_size = match file.metadata().await {
-            Ok(meta) => meta.len(),
-            Err(e) => {
-                return Response::new(
-                    ErrNo::IO,
-                    format!("获取文件大小失败: {:?}, {}", file_path, e),
-                    Default::default(),
-                )
-            }
+        let planner = match PartPlanner::new(file_size, part_size, max_threads) {
+            Ok(planner) => planner,
+            Err(e) => return e,
         };
-        let mut part_number = 1;
-        let mut etag_map = HashMap::new();
-        let upload_id_response = self
-            .put_object_get_upload_id(key, content_type.clone(), storage_class, acl_header.clone())
-            .await;
-        if upload_id_response.error_no != ErrNo::SUCCESS {
-            return upload_id_response;
-        }
-        let upload_id = String::from_utf8_lossy(&upload_id_response.result[..]).to_string();
-        // 默认20个线程
-        let max_threads = max_threads.unwrap_or(20);
-        let mut tasks = Vec::new();
-        let mut upload_bytes = 0;
-        let mut part_number1 = 1;
-        loop {
-            if upload_bytes >= file_size {
-                break;
-            }
-            let mut part_size1 = part_size;
-            let last_bytes = file_size - upload_bytes;
-            // 倒数第二次上传后剩余小于1M，附加到倒数第二次上传
-            if last_bytes < part_size + PART_MIN_SIZE && last_bytes < PART_MAX_SIZE {
-                part_size1 = last_bytes;
-            }
-            let mut body: Vec<u8> = vec![0; part_size1 as usize];
-            if let Err(e) = file.read_exact(&mut body).await {
-                // 调用清理
-                self.abort_object_part(key, &upload_id).await;
-                return Response::new(
-                    ErrNo::IO,
-                    format!("读取文件失败: {:?}, {}", file_path, e),
-                    Default::default(),
-                );
-            }
-            upload_bytes += part_size1;
-            if tasks.len() < max_threads as usize {
-                let key = key.to_string();
-                let upload_id = upload_id.clone();
-                let this = self.clone();
-                let acl_header = acl_header.clone();
-                let content_type = content_type.clone();
-                let handle = tokio::spawn(async move {
-                    // 尝试10次
-                    let mut try_times = 10;
-                    let mut resp = Response::default();
-                    while try_times > 0 {
-                        try_times -= 1;
-                        resp = this
-                            .clone()
-                            .put_object_part(
-                                &key,
-                                &upload_id,
-                                part_number,
-                                body.clone(),
-                                part_size1,
-                                content_type.clone(),
-                                acl_header.clone(),
-                            )
-                            .await;
-                        if resp.error_no != ErrNo::SUCCESS {
-                            // 调用清理
-                            if try_times == 0 {
-                                this.abort_object_part(&key, upload_id.as_str()).await;
-                            }
-                        } else {
-                            break;
-                        }
-                    }
-                    resp
-                });
-                tasks.push(handle);
-                part_number += 1;
-            } else {
-                for task in tasks {
-                    let response = task.await.unwrap();
-                    if response.error_no != ErrNo::SUCCESS {
-                        return response;
+        self.upload_big_object(
+            file_path,
+            key,
+            content_type,
+            storage_class,
+            acl_header,
+            planner,
+            None,
+        )
+        .await
+    }
+
+    /// 上传本地大文件，自适应分片大小及并发数，带进度条
+    /// <https://cloud.tencent.com/document/product/436/7749>
+    /// 见[`put_big_object_adaptive()`]
+    /// # 参数
+    /// - file_path: 文件路径
+    /// - key: 上传文件的key，如test/Cargo.lock
+    /// - content_type: 文件类型
+    /// - storage_class: 存储类型`StorageClassEnum` 默认STANDARD
+    /// - acl_header: 请求控制
+    /// - max_threads: 并发数上限，要求1-1000之间，默认20
+    /// - progress_style: 进度条样式
+    ///
+    /// [`put_big_object_adaptive()`]: Self::put_big_object_adaptive
+    #[cfg(feature = "progress-bar")]
+    pub async fn put_big_object_adaptive_progress_bar(
+        self,
+        file_path: &PathBuf,
+        key: &str,
+        content_type: Option<mime::Mime>,
+        storage_class: Option<StorageClassEnum>,
+        acl_header: Option<acl::AclHeader>,
+        max_threads: Option<u64>,
+        progress_style: Option<ProgressStyle>,
+    ) -> Response {
+        let file_size = match get_file_size(file_path).await {
+            Ok(size) => size,
+            Err(e) => return e,
+        };
+        let planner = match PartPlanner::adaptive(file_size, max_threads) {
+            Ok(planner) => planner,
+            Err(e) => return e,
+        };
+        self.upload_big_object(
+            file_path,
+            key,
+            content_type,
+            storage_class,
+            acl_header,
+            planner,
+            Some(get_progress_style(progress_style)),
+        )
+        .await
+    }
+
+    /// 上传本地大文件，自适应分片大小及并发数，无进度条
+    /// <https://cloud.tencent.com/document/product/436/7749>
+    /// 从较小的分片和并发数开始，每完成一轮分片根据实测吞吐量调整：吞吐量上升时继续沿当前方向调整并发数，
+    /// 下降时反向调整；分片大小调整为单个连接约5秒的上传量，同时保证分片数量不超过10000
+    /// # 参数
+    /// - file_path: 文件路径
+    /// - key: 上传文件的key，如test/Cargo.lock
+    /// - content_type: 文件类型
+    /// - storage_class: 存储类型`StorageClassEnum` 默认STANDARD
+    /// - acl_header: 请求控制
+    /// - max_threads: 并发数上限，要求1-1000之间，默认20
+    ///
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// use qcos::objects::StorageClassEnum;
+    /// use mime;
+    /// use std::path::PathBuf;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let res = client.put_big_object_adaptive(&PathBuf::from("Cargo.toml"),"Cargo.toml", Some(mime::TEXT_PLAIN_UTF_8), Some(StorageClassEnum::STANDARD), None, Some(50)).await;
+    /// };
+    /// ```
+    pub async fn put_big_object_adaptive(
+        self,
+        file_path: &PathBuf,
+        key: &str,
+        content_type: Option<mime::Mime>,
+        storage_class: Option<StorageClassEnum>,
+        acl_header: Option<acl::AclHeader>,
+        max_threads: Option<u64>,
+    ) -> Response {
+        let file_size = match get_file_size(file_path).await {
+            Ok(size) => size,
+            Err(e) => return e,
+        };
+        let planner = match PartPlanner::adaptive(file_size, max_threads) {
+            Ok(planner) => planner,
+            Err(e) => return e,
+        };
+        self.upload_big_object(
+            file_path,
+            key,
+            content_type,
+            storage_class,
+            acl_header,
+            planner,
+            None,
+        )
+        .await
+    }
+
+    /// 分块上传本地文件，分片大小及并发数由`planner`决定，`progress`不为空时显示进度条
+    #[cfg_attr(not(feature = "progress-bar"), allow(unused_variables))]
+    async fn upload_big_object(
+        self,
+        file_path: &Path,
+        key: &str,
+        content_type: Option<mime::Mime>,
+        storage_class: Option<StorageClassEnum>,
+        acl_header: Option<acl::AclHeader>,
+        mut planner: PartPlanner,
+        progress: Option<PartProgress>,
+    ) -> Response {
+        let upload_id_response = self
+            .put_object_get_upload_id(key, content_type.clone(), storage_class, acl_header.clone())
+            .await;
+        if upload_id_response.error_no != ErrNo::SUCCESS {
+            return upload_id_response;
+        }
+        let upload_id = String::from_utf8_lossy(&upload_id_response.result[..]).to_string();
+        let mut etag_map = HashMap::new();
+        // 分片号对应的CRC64及分片大小
+        let mut crc_map = HashMap::new();
+        let mut tasks = JoinSet::new();
+        let mut part_number = 1;
+        let mut upload_bytes = 0;
+        #[cfg(feature = "progress-bar")]
+        let multi = MultiProgress::new();
+        while upload_bytes < planner.file_size {
+            // 达到最大并发数时，等待任意一个分片完成后再启动新的分片
+            while tasks.len() >= planner.threads() {
+                match join_next_part(&mut tasks, &mut etag_map).await {
+                    Ok(outcome) => {
+                        planner.record(outcome.part_size);
+                        if let Some(crc) = outcome.crc64 {
+                            crc_map.insert(outcome.part_number, (crc, outcome.part_size));
+                        }
+                    }
+                    Err(resp) => {
+                        tasks.abort_all();
+                        // 调用清理
+                        self.abort_object_part(key, upload_id.as_str()).await;
+                        return resp;
                     }
-                    etag_map.insert(part_number1, response.headers["etag"].clone());
-                    part_number1 += 1;
                 }
-                tasks = Vec::new();
             }
+            let part_size = planner.next_part_size(upload_bytes, part_number);
+            let offset = upload_bytes;
+            upload_bytes += part_size;
+            let key = key.to_string();
+            let upload_id = upload_id.clone();
+            let this = self.clone();
+            let acl_header = acl_header.clone();
+            let content_type = content_type.clone();
+            let file_path = file_path.to_path_buf();
+            #[cfg(feature = "progress-bar")]
+            let pb = progress.as_ref().map(|sty| {
+                let pb = multi.add(ProgressBar::new(part_size));
+                pb.set_style(sty.clone());
+                pb
+            });
+            tasks.spawn(async move {
+                // 开启完整性校验时先读取一遍分片计算校验值，分片数据不会整体读入内存
+                let checksum = if this.get_integrity_check() {
+                    match checksum_part(&file_path, offset, part_size).await {
+                        Ok(checksum) => Some(checksum),
+                        Err(e) => {
+                            return PartOutcome {
+                                part_number,
+                                part_size,
+                                crc64: None,
+                                response: Response::new(
+                                    ErrNo::IO,
+                                    format!("读取文件失败: {:?}, {}", file_path, e),
+                                    Default::default(),
+                                ),
+                            }
+                        }
+                    }
+                } else {
+                    None
+                };
+                // 尝试10次
+                let mut try_times = 10;
+                let mut resp = Response::default();
+                while try_times > 0 {
+                    try_times -= 1;
+                    // 每次尝试都重新打开文件，从分片起始位置读取
+                    #[cfg(feature = "progress-bar")]
+                    let body = match &pb {
+                        Some(pb) => {
+                            pb.set_position(0);
+                            get_part_body_progress_bar(&file_path, offset, part_size, pb.clone())
+                                .await
+                        }
+                        None => get_part_body(&file_path, offset, part_size).await,
+                    };
+                    #[cfg(not(feature = "progress-bar"))]
+                    let body = get_part_body(&file_path, offset, part_size).await;
+                    resp = match body {
+                        Ok(body) => {
+                            this.clone()
+                                .put_object_part_checked(
+                                    &key,
+                                    &upload_id,
+                                    part_number,
+                                    body,
+                                    part_size,
+                                    content_type.clone(),
+                                    acl_header.clone(),
+                                    checksum.clone(),
+                                )
+                                .await
+                        }
+                        Err(e) => Response::new(
+                            ErrNo::IO,
+                            format!("读取文件失败: {:?}, {}", file_path, e),
+                            Default::default(),
+                        ),
+                    };
+                    if resp.error_no == ErrNo::SUCCESS {
+                        #[cfg(feature = "progress-bar")]
+                        if let Some(pb) = &pb {
+                            pb.finish();
+                        }
+                        break;
+                    }
+                }
+                PartOutcome {
+                    part_number,
+                    part_size,
+                    crc64: checksum.map(|x| x.1),
+                    response: resp,
+                }
+            });
+            part_number += 1;
         }
-        if !tasks.is_empty() {
-            for task in tasks {
-                let response = task.await.unwrap();
-                if response.error_no != ErrNo::SUCCESS {
-                    return response;
+        while !tasks.is_empty() {
+            match join_next_part(&mut tasks, &mut etag_map).await {
+                Ok(outcome) => {
+                    if let Some(crc) = outcome.crc64 {
+                        crc_map.insert(outcome.part_number, (crc, outcome.part_size));
+                    }
+                }
+                Err(resp) => {
+                    tasks.abort_all();
+                    // 调用清理
+                    self.abort_object_part(key, upload_id.as_str()).await;
+                    return resp;
                 }
-                etag_map.insert(part_number1, response.headers["etag"].clone());
-                part_number1 += 1;
             }
         }
         // 调用合并
@@ -493,6 +1088,18 @@ impl client::Client {
         if resp.error_no != ErrNo::SUCCESS {
             // 调用清理
             self.abort_object_part(key, upload_id.as_str()).await;
+            return resp;
+        }
+        if self.get_integrity_check() {
+            // 按分片号顺序合并各分片的CRC64，与整个对象的CRC64比较
+            let mut crcs: Vec<(u64, (u64, u64))> = crc_map.into_iter().collect();
+            crcs.sort_by_key(|x| x.0);
+            let crc = crcs
+                .iter()
+                .fold(0, |acc, (_, (crc, size))| crc64_combine(acc, *crc, *size));
+            if let Err(e) = verify_crc64(&resp.headers, crc) {
+                return e;
+            }
         }
         resp
     }
@@ -523,6 +1130,33 @@ impl client::Client {
         content_type: Option<mime::Mime>,
         acl_header: Option<acl::AclHeader>,
         progress_style: Option<ProgressStyle>,
+    ) -> Response {
+        self.put_object_binary_progress_bar_with_options(
+            file,
+            key,
+            file_size,
+            content_type,
+            acl_header,
+            ObjectOptions::new(),
+            progress_style,
+        )
+        .await
+    }
+
+    /// 同[`put_object_binary_progress_bar`](Self::put_object_binary_progress_bar)，可传入条件请求头、版本ID等可选项
+    /// - options: 条件请求头、版本ID等可选项
+    #[cfg(feature = "progress-bar")]
+    pub async fn put_object_binary_progress_bar_with_options<
+        T: Into<Body> + Send + Sync + tokio::io::AsyncRead + 'static,
+    >(
+        &self,
+        file: T,
+        key: &str,
+        file_size: u64,
+        content_type: Option<mime::Mime>,
+        acl_header: Option<acl::AclHeader>,
+        options: ObjectOptions,
+        progress_style: Option<ProgressStyle>,
     ) -> Response {
         let reader = ReaderStream::new(file);
         let pb = ProgressBar::new(file_size);
@@ -537,7 +1171,7 @@ impl client::Client {
         });
         let body = Body::wrap_stream(stream);
         let resp = self
-            .put_object_binary(body, key, content_type, acl_header)
+            .put_object_binary_with_options(body, key, content_type, acl_header, options)
             .await;
         pb.finish();
         resp
@@ -570,14 +1204,58 @@ impl client::Client {
         key: &str,
         content_type: Option<mime::Mime>,
         acl_header: Option<acl::AclHeader>,
+    ) -> Response {
+        self.put_object_binary_with_options(
+            file,
+            key,
+            content_type,
+            acl_header,
+            ObjectOptions::new(),
+        )
+        .await
+    }
+
+    /// 同[`put_object_binary`](Self::put_object_binary)，可传入条件请求头、版本ID等可选项
+    /// - options: 条件请求头等可选项，如`If-None-Match: *`表示对象不存在时才上传
+    ///
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// use qcos::options::ObjectOptions;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let buffer = std::fs::read("Cargo.toml").unwrap();
+    /// // 仅当对象的ETag仍然是上次读取到的值时才覆盖
+    /// let mut options = ObjectOptions::new();
+    /// options.insert_if_match("\"e0ad4fa8d1f4a2ab8a8ba1ab0e6b7a4e\"").unwrap();
+    /// let res = client.put_object_binary_with_options(buffer, "Cargo.toml", Some(mime::TEXT_PLAIN_UTF_8), None, options).await;
+    /// assert!(res.error_message.contains("403"));
+    /// };
+    /// ```
+    pub async fn put_object_binary_with_options<T: Into<Body> + Send>(
+        &self,
+        file: T,
+        key: &str,
+        content_type: Option<mime::Mime>,
+        acl_header: Option<acl::AclHeader>,
+        options: ObjectOptions,
     ) -> Response {
         let body: Body = file.into();
         let bytes = body.as_bytes();
         if bytes.is_none() {
             return Response::new(ErrNo::IO, "不是内存对象".to_owned(), Default::default());
         }
-        let file_size = bytes.unwrap().len();
+        let bytes = bytes.unwrap();
+        let file_size = bytes.len();
+        // 开启完整性校验时计算CRC64，并发送Content-MD5
+        let crc = self.get_integrity_check().then(|| crc64(bytes));
         let mut headers = self.get_common_headers();
+        if crc.is_some() {
+            headers.insert(
+                HeaderName::from_static("content-md5"),
+                HeaderValue::from_str(&content_md5(bytes)).unwrap(),
+            );
+        }
         headers.insert(
             CONTENT_TYPE,
             HeaderValue::from_str(
@@ -588,6 +1266,7 @@ impl client::Client {
             .unwrap(),
         );
         headers.insert(CONTENT_LENGTH, HeaderValue::from(file_size));
+        options.extend_headers(&mut headers);
         let url_path = self.get_path_from_object_key(key);
         headers =
             self.get_headers_with_auth("put", url_path.as_str(), acl_header, Some(headers), None);
@@ -600,7 +1279,13 @@ impl client::Client {
             Some(body),
         )
         .await;
-        self.make_response(resp)
+        let resp = self.make_response(resp);
+        if let (ErrNo::SUCCESS, Some(crc)) = (resp.error_no, crc) {
+            if let Err(e) = verify_crc64(&resp.headers, crc) {
+                return e;
+            }
+        }
+        resp
     }
 
     /// 删除文件
@@ -633,10 +1318,208 @@ impl client::Client {
         }
     }
 
+    /// 删除对象的指定版本，存储桶需开启版本控制
+    /// <https://cloud.tencent.com/document/product/436/7743>
+    /// # 参数
+    /// - key: 文件的key，如test/Cargo.lock
+    /// - version_id: 版本ID，可以通过[`list_object_versions()`]获取
+    ///
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let res = client.delete_object_version("Cargo.toml", "MTg0NDUxNTc1NjIzMTQ1MDAwODg").await;
+    /// };
+    /// ```
+    ///
+    /// [`list_object_versions()`]: Self::list_object_versions
+    pub async fn delete_object_version(&self, key: &str, version_id: &str) -> Response {
+        let url_path = self.get_path_from_object_key(key);
+        let mut query = HashMap::new();
+        query.insert("versionId".to_string(), version_id.to_string());
+        let headers = self.get_headers_with_auth(
+            "delete",
+            url_path.as_str(),
+            None,
+            None,
+            Some(query.clone()),
+        );
+        let resp = Request::delete(
+            self.get_full_url_from_path(url_path.as_str()).as_str(),
+            Some(&query),
+            Some(&headers),
+            None,
+            None,
+        )
+        .await;
+        self.make_response(resp)
+    }
+
+    /// 批量删除对象，单次最多[`MAX_DELETE_OBJECTS`]个
+    /// <https://cloud.tencent.com/document/product/436/8289>
+    /// # 参数
+    /// - objects: 要删除的对象，可指定版本
+    /// - quiet: 为`true`时结果中只包含删除失败的对象
+    ///
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// use qcos::objects::ObjectIdentifier;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let objects = vec![ObjectIdentifier::new("a.txt", ""), ObjectIdentifier::new("b.txt", "")];
+    /// if let Ok(res) = client.delete_objects(&objects, true).await {
+    ///     for e in res.error {
+    ///         println!("{} {}", e.key, e.code);
+    ///     }
+    /// }
+    /// };
+    /// ```
+    pub async fn delete_objects(
+        &self,
+        objects: &[ObjectIdentifier],
+        quiet: bool,
+    ) -> Result<DeleteResult, Response> {
+        if objects.is_empty() || objects.len() > MAX_DELETE_OBJECTS {
+            return Err(Response::new(
+                ErrNo::PARAM,
+                format!("批量删除的对象数量需在1-{}之间", MAX_DELETE_OBJECTS),
+                Default::default(),
+            ));
+        }
+        let delete = Delete {
+            quiet,
+            object: objects.to_vec(),
+        };
+        let body = match to_string(&delete) {
+            Ok(s) => s,
+            Err(e) => {
+                return Err(Response::new(
+                    ErrNo::ENCODE,
+                    e.to_string(),
+                    Default::default(),
+                ))
+            }
+        };
+        let mut query = HashMap::new();
+        query.insert("delete".to_string(), String::new());
+        let mut headers = self.get_common_headers();
+        headers.insert(
+            CONTENT_TYPE,
+            HeaderValue::from_str("application/xml").unwrap(),
+        );
+        headers.insert(
+            HeaderName::from_static("content-md5"),
+            HeaderValue::from_str(&content_md5(body.as_bytes())).unwrap(),
+        );
+        let headers =
+            self.get_headers_with_auth("post", "/", None, Some(headers), Some(query.clone()));
+        let resp = Request::post(
+            self.get_full_url_from_path("/").as_str(),
+            Some(&query),
+            Some(&headers),
+            None,
+            None,
+            Some(body),
+        )
+        .await;
+        let resp = self.make_response(resp);
+        // quiet模式下全部删除成功时可能没有返回内容
+        if resp.error_no == ErrNo::SUCCESS && resp.result.is_empty() {
+            return Ok(DeleteResult::default());
+        }
+        self.make_xml_response(Ok(resp))
+    }
+
+    /// 复制对象，源对象需与目标对象在同一个存储桶，单次复制的对象不能超过5GB
+    /// <https://cloud.tencent.com/document/product/436/10881>
+    /// # 参数
+    /// - key: 目标对象的key
+    /// - source_key: 源对象的key
+    /// - source_version_id: 源对象的版本ID，不传表示最新版本
+    /// - storage_class: 目标对象的存储类型，不传时为STANDARD
+    /// - acl_header: 请求控制
+    ///
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let res = client.copy_object("Cargo.toml.bak", "Cargo.toml", None, None, None).await;
+    /// };
+    /// ```
+    pub async fn copy_object(
+        &self,
+        key: &str,
+        source_key: &str,
+        source_version_id: Option<&str>,
+        storage_class: Option<StorageClassEnum>,
+        acl_header: Option<acl::AclHeader>,
+    ) -> Result<CopyObjectResult, Response> {
+        let mut headers = self.get_common_headers();
+        if let Some(storage_class) = storage_class {
+            headers.insert(
+                HeaderName::from_static("x-cos-storage-class"),
+                HeaderValue::from_str(&String::from(storage_class)).unwrap(),
+            );
+        }
+        self.copy_object_with_headers(key, source_key, source_version_id, headers, acl_header)
+            .await
+    }
+
+    /// 复制对象，`headers`为额外的请求头，如存储类型、元数据
+    pub(crate) async fn copy_object_with_headers(
+        &self,
+        key: &str,
+        source_key: &str,
+        source_version_id: Option<&str>,
+        mut headers: HeaderMap,
+        acl_header: Option<acl::AclHeader>,
+    ) -> Result<CopyObjectResult, Response> {
+        let url_path = self.get_path_from_object_key(key);
+        headers.insert(
+            HeaderName::from_static("x-cos-copy-source"),
+            HeaderValue::from_str(&self.get_copy_source(source_key, source_version_id)).unwrap(),
+        );
+        let headers =
+            self.get_headers_with_auth("put", url_path.as_str(), acl_header, Some(headers), None);
+        let resp = self.make_response(
+            Request::put(
+                self.get_full_url_from_path(url_path.as_str()).as_str(),
+                None,
+                Some(&headers),
+                None,
+                None,
+                None as Option<Body>,
+            )
+            .await,
+        );
+        let version_id = resp.version_id().map(String::from);
+        let mut result: CopyObjectResult = self.make_xml_response(Ok(resp))?;
+        result.version_id = version_id;
+        Ok(result)
+    }
+
+    /// 生成`x-cos-copy-source`请求头的值
+    pub(crate) fn get_copy_source(&self, source_key: &str, version_id: Option<&str>) -> String {
+        let path = self.get_path_from_object_key(source_key);
+        let path = path
+            .split('/')
+            .map(|x| urlencoding::encode(x).into_owned())
+            .collect::<Vec<_>>()
+            .join("/");
+        match version_id {
+            Some(version_id) => format!("{}{}?versionId={}", self.get_host(), path, version_id),
+            None => format!("{}{}", self.get_host(), path),
+        }
+    }
+
     /// 下载文件二进制数据，有进度条
     /// <https://cloud.tencent.com/document/product/436/7753>
     /// # 参数
     /// - key: 文件的key，如test/Cargo.lock
+    /// - threads: 下载线程数量，默认5
     /// - progress_style: 进度条样式
     ///
     /// # Examples
@@ -654,11 +1537,29 @@ impl client::Client {
         threads: Option<u8>,
         progress_style: Option<ProgressStyle>,
     ) -> Response {
-        let size = self.get_object_size(key).await;
-        if size < 0 {
-            return Response::new(ErrNo::STATUS, String::from("object not exist"), Vec::new());
-        }
-        let size = size as usize;
+        self.get_object_binary_progress_bar_with_options(
+            key,
+            threads,
+            ObjectOptions::new(),
+            progress_style,
+        )
+        .await
+    }
+
+    /// 同[`get_object_binary_progress_bar`](Self::get_object_binary_progress_bar)，可传入条件请求头、版本ID等可选项
+    /// - options: 条件请求头、版本ID等可选项，条件不满足时返回`ErrNo::NOT_MODIFIED`或`ErrNo::PRECONDITION_FAILED`
+    #[cfg(feature = "progress-bar")]
+    pub async fn get_object_binary_progress_bar_with_options(
+        &self,
+        key: &str,
+        threads: Option<u8>,
+        options: ObjectOptions,
+        progress_style: Option<ProgressStyle>,
+    ) -> Response {
+        let (size, headers, query) = match self.prepare_download(key, options).await {
+            Ok(x) => x,
+            Err(e) => return e,
+        };
         let multi = MultiProgress::new();
         let sty = match progress_style {
             Some(sty)=>sty,
@@ -670,12 +1571,12 @@ impl client::Client {
             threads = 1;
         }
         let url_path = self.get_path_from_object_key(key);
-        let headers = self.get_headers_with_auth("get", url_path.as_str(), None, None, None);
         let url = self.get_full_url_from_path(url_path.as_str());
         let part_size = size / threads;
         let mut handles = Vec::new();
         for i in 0..threads {
             let mut headers = headers.clone();
+            let query = query.clone();
             let url = url.clone();
             let multi = multi.clone();
             let sty = sty.clone();
@@ -693,32 +1594,33 @@ impl client::Client {
                 pb.set_style(sty);
                 let range = format!("bytes={}-{}", i * part_size, range);
                 headers.insert(RANGE, HeaderValue::from_str(&range).unwrap());
-                // let resp = Request::get(&url, None, Some(&headers)).await;
-                let mut resp = reqwest::Client::new()
+                let resp = match reqwest::Client::new()
                     .get(url)
+                    .query(&query)
                     .headers(headers)
                     .send()
                     .await
-                    .unwrap();
-                let mut data = Vec::new();
-                while let Some(chunk) = resp.chunk().await.unwrap() {
-                    pb.inc(chunk.len() as u64);
-                    data.push(chunk);
-                }
-                let resp = Response::data_success(data.concat());
+                {
+                    Ok(resp) => read_range_progress_bar(resp, &pb).await,
+                    Err(e) => e.into(),
+                };
                 pb.finish();
                 resp
             });
             handles.push(handle);
         }
+        let mut verifier = RangeVerifier::new(size, threads, self.get_integrity_check());
         let mut data = Vec::new();
         for handle in handles {
             let response = handle.await.unwrap();
-            if response.error_no != ErrNo::SUCCESS {
-                return response;
+            if let Err(e) = verifier.verify(&response) {
+                return e;
             }
             data.extend(response.result);
         }
+        if let Err(e) = verifier.finish() {
+            return e;
+        }
         Response::data_success(data)
     }
 
@@ -738,10 +1640,23 @@ impl client::Client {
     /// };
     /// ```
     pub async fn get_object(&self, key: &str, file_name: &str, threads: Option<u8>) -> Response {
-        let size = self.get_object_size(key).await;
-        if size < 0 {
-            return Response::new(ErrNo::STATUS, String::from("object not exist"), Vec::new());
-        }
+        self.get_object_with_options(key, file_name, threads, ObjectOptions::new())
+            .await
+    }
+
+    /// 同[`get_object`](Self::get_object)，可传入条件请求头、版本ID等可选项
+    /// - options: 条件请求头、版本ID等可选项，条件不满足时返回`ErrNo::NOT_MODIFIED`或`ErrNo::PRECONDITION_FAILED`
+    pub async fn get_object_with_options(
+        &self,
+        key: &str,
+        file_name: &str,
+        threads: Option<u8>,
+        options: ObjectOptions,
+    ) -> Response {
+        let (size, headers, query) = match self.prepare_download(key, options).await {
+            Ok(x) => x,
+            Err(e) => return e,
+        };
         let file_path = PathBuf::from(file_name);
         if let Some(parent_file_path) = file_path.parent() {
             if !parent_file_path.exists() {
@@ -759,19 +1674,18 @@ impl client::Client {
                 );
             }
         }
-        let size = size as usize;
         let mut threads = threads.unwrap_or(5) as usize;
         // 小于1KB只启用1个线程
         if size < 1024 {
             threads = 1;
         }
         let url_path = self.get_path_from_object_key(key);
-        let headers = self.get_headers_with_auth("get", url_path.as_str(), None, None, None);
         let url = self.get_full_url_from_path(url_path.as_str());
         let part_size = size / threads;
         let mut handles = Vec::new();
         for i in 0..threads {
             let mut headers = headers.clone();
+            let query = query.clone();
             let url = url.clone();
             let handle = tokio::spawn(async move {
                 // 最后一个线程下载全部
@@ -782,14 +1696,15 @@ impl client::Client {
                 };
                 let range = format!("bytes={}-{}", i * part_size, range);
                 headers.insert(RANGE, HeaderValue::from_str(&range).unwrap());
-                Request::get(&url, None, Some(&headers)).await
+                Request::get(&url, Some(&query), Some(&headers)).await
             });
             handles.push(handle);
         }
+        let mut verifier = RangeVerifier::new(size, threads, self.get_integrity_check());
         for handle in handles {
             let response: Response = self.make_response(handle.await.unwrap());
-            if response.error_no != ErrNo::SUCCESS {
-                return response;
+            if let Err(e) = verifier.verify(&response) {
+                return e;
             }
             match io::copy(&mut Cursor::new(response.result), &mut output_file).await {
                 Ok(_) => {
@@ -806,6 +1721,9 @@ impl client::Client {
                 }
             }
         }
+        if let Err(e) = verifier.finish() {
+            return e;
+        }
         Response::default()
     }
 
@@ -833,10 +1751,31 @@ impl client::Client {
         threads: Option<u8>,
         progress_style: Option<ProgressStyle>,
     ) -> Response {
-        let size = self.get_object_size(key).await;
-        if size < 0 {
-            return Response::new(ErrNo::STATUS, String::from("object not exist"), Vec::new());
-        }
+        self.get_object_progress_bar_with_options(
+            key,
+            file_name,
+            threads,
+            ObjectOptions::new(),
+            progress_style,
+        )
+        .await
+    }
+
+    /// 同[`get_object_progress_bar`](Self::get_object_progress_bar)，可传入条件请求头、版本ID等可选项
+    /// - options: 条件请求头、版本ID等可选项，条件不满足时返回`ErrNo::NOT_MODIFIED`或`ErrNo::PRECONDITION_FAILED`
+    #[cfg(feature = "progress-bar")]
+    pub async fn get_object_progress_bar_with_options(
+        &self,
+        key: &str,
+        file_name: &str,
+        threads: Option<u8>,
+        options: ObjectOptions,
+        progress_style: Option<ProgressStyle>,
+    ) -> Response {
+        let (size, headers, query) = match self.prepare_download(key, options).await {
+            Ok(x) => x,
+            Err(e) => return e,
+        };
         let file_path = PathBuf::from(file_name);
         if let Some(parent_file_path) = file_path.parent() {
             if !parent_file_path.exists() {
@@ -854,7 +1793,6 @@ impl client::Client {
                 );
             }
         }
-        let size = size as usize;
         let multi = MultiProgress::new();
         let sty = match progress_style {
             Some(sty)=>sty,
@@ -866,12 +1804,12 @@ impl client::Client {
             threads = 1;
         }
         let url_path = self.get_path_from_object_key(key);
-        let headers = self.get_headers_with_auth("get", url_path.as_str(), None, None, None);
         let url = self.get_full_url_from_path(url_path.as_str());
         let part_size = size / threads;
         let mut handles = Vec::new();
         for i in 0..threads {
             let mut headers = headers.clone();
+            let query = query.clone();
             let url = url.clone();
             let multi = multi.clone();
             let sty = sty.clone();
@@ -889,28 +1827,26 @@ impl client::Client {
                 pb.set_style(sty);
                 let range = format!("bytes={}-{}", i * part_size, range);
                 headers.insert(RANGE, HeaderValue::from_str(&range).unwrap());
-                // let resp = Request::get(&url, None, Some(&headers)).await;
-                let mut resp = reqwest::Client::new()
+                let resp = match reqwest::Client::new()
                     .get(url)
+                    .query(&query)
                     .headers(headers)
                     .send()
                     .await
-                    .unwrap();
-                let mut data = Vec::new();
-                while let Some(chunk) = resp.chunk().await.unwrap() {
-                    pb.inc(chunk.len() as u64);
-                    data.push(chunk);
-                }
-                let resp = Response::data_success(data.concat());
+                {
+                    Ok(resp) => read_range_progress_bar(resp, &pb).await,
+                    Err(e) => e.into(),
+                };
                 pb.finish();
                 resp
             });
             handles.push(handle);
         }
+        let mut verifier = RangeVerifier::new(size, threads, self.get_integrity_check());
         for handle in handles {
             let response = handle.await.unwrap();
-            if response.error_no != ErrNo::SUCCESS {
-                return response;
+            if let Err(e) = verifier.verify(&response) {
+                return e;
             }
             match io::copy(&mut Cursor::new(response.result), &mut output_file).await {
                 Ok(_) => {
@@ -927,6 +1863,9 @@ impl client::Client {
                 }
             }
         }
+        if let Err(e) = verifier.finish() {
+            return e;
+        }
         Response::default()
     }
 
@@ -939,9 +1878,6 @@ impl client::Client {
         storage_class: Option<StorageClassEnum>,
         acl_header: Option<acl::AclHeader>,
     ) -> Response {
-        let mut query = HashMap::new();
-        query.insert("uploads".to_string(), String::new());
-        let url_path = self.get_path_from_object_key(key);
         let mut headers = self.get_common_headers();
         headers.insert(
             CONTENT_TYPE,
@@ -959,6 +1895,20 @@ impl client::Client {
             ))
             .unwrap(),
         );
+        self.initiate_multipart_upload(key, headers, acl_header)
+            .await
+    }
+
+    /// 初始化分块上传，`headers`为对象的元数据等请求头，成功时`result`为UploadId
+    pub(crate) async fn initiate_multipart_upload(
+        &self,
+        key: &str,
+        headers: HeaderMap,
+        acl_header: Option<acl::AclHeader>,
+    ) -> Response {
+        let mut query = HashMap::new();
+        query.insert("uploads".to_string(), String::new());
+        let url_path = self.get_path_from_object_key(key);
         let headers = self.get_headers_with_auth(
             "post",
             url_path.as_str(),
@@ -1028,6 +1978,7 @@ impl client::Client {
 
     /// 分块上传文件，不带进度条
     /// <https://cloud.tencent.com/document/product/436/7750>
+    /// 开启完整性校验且`body`为内存数据时，会发送`Content-MD5`并校验返回的CRC64
     pub async fn put_object_part<T: Into<Body> + Send>(
         self,
         key: &str,
@@ -1038,6 +1989,42 @@ impl client::Client {
         content_type: Option<mime::Mime>,
         acl_header: Option<acl::AclHeader>,
     ) -> Response {
+        self.put_object_part_checked(
+            key,
+            upload_id,
+            part_number,
+            body,
+            file_size,
+            content_type,
+            acl_header,
+            None,
+        )
+        .await
+    }
+
+    /// 同[`put_object_part()`]，`checksum`为分片的`Content-MD5`及CRC64，
+    /// 为空时若开启了完整性校验且`body`为内存数据，会根据`body`计算
+    ///
+    /// [`put_object_part()`]: Self::put_object_part
+    pub(crate) async fn put_object_part_checked<T: Into<Body> + Send>(
+        self,
+        key: &str,
+        upload_id: &str,
+        part_number: u64,
+        body: T,
+        file_size: u64,
+        content_type: Option<mime::Mime>,
+        acl_header: Option<acl::AclHeader>,
+        checksum: Option<(String, u64)>,
+    ) -> Response {
+        let body: Body = body.into();
+        let checksum = match (checksum, body.as_bytes()) {
+            (Some(checksum), _) => Some(checksum),
+            (None, Some(bytes)) if self.get_integrity_check() => {
+                Some((content_md5(bytes), crc64(bytes)))
+            }
+            _ => None,
+        };
         let mut headers = self.get_common_headers();
         headers.insert(
             CONTENT_TYPE,
@@ -1049,6 +2036,12 @@ impl client::Client {
             .unwrap(),
         );
         headers.insert(CONTENT_LENGTH, HeaderValue::from(file_size));
+        if let Some((md5, _)) = &checksum {
+            headers.insert(
+                HeaderName::from_static("content-md5"),
+                HeaderValue::from_str(md5).unwrap(),
+            );
+        }
         let url_path = self.get_path_from_object_key(key);
         let mut query = HashMap::new();
         query.insert("partNumber".to_string(), part_number.to_string());
@@ -1060,7 +2053,6 @@ impl client::Client {
             Some(headers),
             Some(query.clone()),
         );
-        let body: Body = body.into();
         let resp = Request::put(
             self.get_full_url_from_path(url_path.as_str()).as_str(),
             Some(&query),
@@ -1070,12 +2062,38 @@ impl client::Client {
             Some(body),
         )
         .await;
-        self.make_response(resp)
+        let resp = self.make_response(resp);
+        if let (ErrNo::SUCCESS, Some((_, crc))) = (resp.error_no, checksum) {
+            if let Err(e) = verify_crc64(&resp.headers, crc) {
+                return e;
+            }
+        }
+        resp
     }
 
     /// 完成分块上传
     /// <https://cloud.tencent.com/document/product/436/7742>
-    async fn put_object_complete_part(
+    /// # 参数
+    /// - key: 文件的key，如test/Cargo.lock
+    /// - etag_map: 分片号及对应的ETag，可由[`list_parts()`]的结果生成
+    /// - upload_id: 分块上传的UploadId
+    ///
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// use std::collections::HashMap;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let upload_id = "1585130821cbb7df1d11846c073ad648e8f33b087cec2381df437acdc833cf654b9ecc6361";
+    /// if let Ok(res) = client.list_parts("Cargo.toml", upload_id, 0, 1000).await {
+    ///     let etag_map: HashMap<u64, String> = res.part.into_iter().map(|x| (x.part_number, x.etag)).collect();
+    ///     client.put_object_complete_part("Cargo.toml", etag_map, upload_id).await;
+    /// }
+    /// };
+    /// ```
+    ///
+    /// [`list_parts()`]: Self::list_parts
+    pub async fn put_object_complete_part(
         &self,
         key: &str,
         etag_map: HashMap<u64, String>,
@@ -1096,17 +2114,9 @@ impl client::Client {
             Some(headers),
             Some(query.clone()),
         );
-        let mut parts = Vec::new();
-        // 按part_number排序
-        let mut etag_map_tuple: Vec<(&u64, &String)> = etag_map.iter().collect();
-        etag_map_tuple.sort_by(|a, b| a.0.cmp(b.0));
-        for (k, v) in etag_map_tuple {
-            parts.push(Part {
-                part_number: *k,
-                etag: v.to_string(),
-            })
-        }
-        let complete = CompleteMultipartUpload { part: parts };
+        let complete = CompleteMultipartUpload {
+            part: sorted_parts(&etag_map),
+        };
         let serialized_str = match to_string(&complete) {
             Ok(s) => s,
             Err(e) => return Response::new(ErrNo::ENCODE, e.to_string(), Default::default()),
@@ -1123,6 +2133,53 @@ impl client::Client {
         self.make_response(resp)
     }
 
+    /// 查询指定分块上传中已经上传的分片，单次最多返回1000个
+    /// <https://cloud.tencent.com/document/product/436/7747>
+    /// # 参数
+    /// - key: 文件的key，如test/Cargo.lock
+    /// - upload_id: 分块上传的UploadId
+    /// - part_number_marker: 从该分片号之后开始列出，首次查询为0，之后使用结果中的`next_part_number_marker`
+    /// - max_parts: 最大返回条数，1-1000之间，其他值使用默认值1000
+    ///
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let res = client.list_parts("Cargo.toml", "upload_id", 0, 1000).await;
+    /// assert!(res.unwrap_err().error_message.contains("403"));
+    /// };
+    /// ```
+    pub async fn list_parts(
+        &self,
+        key: &str,
+        upload_id: &str,
+        part_number_marker: u64,
+        max_parts: i32,
+    ) -> Result<ListPartsResult, Response> {
+        let url_path = self.get_path_from_object_key(key);
+        let mut query = HashMap::new();
+        query.insert("uploadId".to_string(), upload_id.to_string());
+        if part_number_marker > 0 {
+            query.insert(
+                "part-number-marker".to_string(),
+                part_number_marker.to_string(),
+            );
+        }
+        if max_parts <= 1000 && max_parts > 0 {
+            query.insert("max-parts".to_string(), max_parts.to_string());
+        }
+        let headers =
+            self.get_headers_with_auth("get", url_path.as_str(), None, None, Some(query.clone()));
+        let resp = Request::get(
+            self.get_full_url_from_path(url_path.as_str()).as_str(),
+            Some(&query),
+            Some(&headers),
+        )
+        .await;
+        self.make_xml_response(resp)
+    }
+
     /// 终止分块上传，清理文件碎片
     /// <https://cloud.tencent.com/document/product/436/7740>
     pub async fn abort_object_part(&self, key: &str, upload_id: &str) -> Response {
@@ -1147,6 +2204,102 @@ impl client::Client {
         self.make_response(resp)
     }
 
+    /// 查询正在进行中的分块上传，单次最多返回1000条
+    /// <https://cloud.tencent.com/document/product/436/7736>
+    /// # 参数
+    /// - prefix: 对象键前缀，为空时不限制
+    /// - key_marker: 从该对象键之后开始列出，与`upload_id_marker`一起用于分页，首次查询为空
+    /// - upload_id_marker: 从该UploadId之后开始列出，首次查询为空
+    /// - max_uploads: 最大返回条数，1-1000之间，其他值使用默认值1000
+    ///
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let res = client.list_multipart_uploads("test/", "", "", 100).await;
+    /// assert!(res.unwrap_err().error_message.contains("403"));
+    /// };
+    /// ```
+    pub async fn list_multipart_uploads(
+        &self,
+        prefix: &str,
+        key_marker: &str,
+        upload_id_marker: &str,
+        max_uploads: i32,
+    ) -> Result<ListMultipartUploadsResult, Response> {
+        let mut query = HashMap::new();
+        query.insert("uploads".to_string(), String::new());
+        if !prefix.is_empty() {
+            query.insert("prefix".to_string(), prefix.to_string());
+        }
+        if !key_marker.is_empty() {
+            query.insert("key-marker".to_string(), key_marker.to_string());
+        }
+        if !upload_id_marker.is_empty() {
+            query.insert("upload-id-marker".to_string(), upload_id_marker.to_string());
+        }
+        if max_uploads <= 1000 && max_uploads > 0 {
+            query.insert("max-uploads".to_string(), max_uploads.to_string());
+        }
+        let headers = self.get_headers_with_auth("get", "/", None, None, Some(query.clone()));
+        let resp = Request::get(
+            self.get_full_url_from_path("/").as_str(),
+            Some(&query),
+            Some(&headers),
+        )
+        .await;
+        self.make_xml_response(resp)
+    }
+
+    /// 终止初始化时间早于`older_than`的分块上传，清理未完成上传遗留的文件碎片，返回被终止的分块上传
+    /// <https://cloud.tencent.com/document/product/436/7736>
+    /// # 参数
+    /// - prefix: 对象键前缀，为空时不限制
+    /// - older_than: 截止时间
+    ///
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// use chrono::{Duration, Utc};
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// // 清理7天前发起的分块上传
+    /// let res = client.abort_stale_uploads("", Utc::now() - Duration::days(7)).await;
+    /// assert!(res.unwrap_err().error_message.contains("403"));
+    /// };
+    /// ```
+    pub async fn abort_stale_uploads(
+        &self,
+        prefix: &str,
+        older_than: DateTime<Utc>,
+    ) -> Result<Vec<Upload>, Response> {
+        let mut aborted = Vec::new();
+        let mut key_marker = String::new();
+        let mut upload_id_marker = String::new();
+        loop {
+            let result = self
+                .list_multipart_uploads(prefix, &key_marker, &upload_id_marker, 1000)
+                .await?;
+            for upload in result.upload {
+                if upload.initiated >= older_than {
+                    continue;
+                }
+                let resp = self.abort_object_part(&upload.key, &upload.upload_id).await;
+                if resp.error_no != ErrNo::SUCCESS {
+                    return Err(resp);
+                }
+                aborted.push(upload);
+            }
+            if !result.is_truncated {
+                break;
+            }
+            key_marker = result.next_key_marker;
+            upload_id_marker = result.next_upload_id_marker;
+        }
+        Ok(aborted)
+    }
+
     /// 获取对象的大小(bytes)， 返回`-1`表示文件不存在
     /// <https://cloud.tencent.com/document/product/436/7745>
     /// # 参数
@@ -1171,10 +2324,204 @@ impl client::Client {
         size
     }
 
+    /// 查询对象的元数据，元数据在返回的`headers`中
+    /// <https://cloud.tencent.com/document/product/436/7745>
+    /// # 参数
+    /// - key: 文件的key，如test/Cargo.lock
+    ///
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let res = client.head_object("Cargo.toml").await;
+    /// assert_eq!(res.status_code(), Some(403));
+    /// };
+    /// ```
+    pub async fn head_object(&self, key: &str) -> Response {
+        self.head_object_with_options(key, ObjectOptions::new())
+            .await
+    }
+
+    /// 同[`head_object`](Self::head_object)，可传入条件请求头、版本ID等可选项
+    /// - options: 条件请求头、版本ID等可选项，条件不满足时返回`ErrNo::NOT_MODIFIED`或`ErrNo::PRECONDITION_FAILED`
+    ///
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// use qcos::objects::ErrNo;
+    /// use qcos::options::ObjectOptions;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let mut options = ObjectOptions::new();
+    /// options.insert_if_none_match("\"e0ad4fa8d1f4a2ab8a8ba1ab0e6b7a4e\"").unwrap();
+    /// let res = client.head_object_with_options("Cargo.toml", options).await;
+    /// if res.error_no == ErrNo::NOT_MODIFIED {
+    ///     // 本地缓存仍然有效
+    /// }
+    /// };
+    /// ```
+    pub async fn head_object_with_options(&self, key: &str, options: ObjectOptions) -> Response {
+        let url_path = self.get_path_from_object_key(key);
+        let mut headers = self.get_common_headers();
+        let mut query = HashMap::new();
+        options.extend_headers(&mut headers);
+        query.extend(options.get_query().clone());
+        let headers = self.get_headers_with_auth(
+            "head",
+            url_path.as_str(),
+            None,
+            Some(headers),
+            Some(query.clone()),
+        );
+        let resp = Request::head(
+            self.get_full_url_from_path(url_path.as_str()).as_str(),
+            Some(&query),
+            Some(&headers),
+        )
+        .await;
+        self.make_response(resp)
+    }
+
+    /// 写入对象的子资源配置，如`tagging`、`acl`，配置序列化为xml作为请求体
+    pub(crate) async fn put_object_config<T: serde::Serialize>(
+        &self,
+        key: &str,
+        resource: &str,
+        config: &T,
+    ) -> Response {
+        let body = match to_string(config) {
+            Ok(s) => s,
+            Err(e) => return Response::new(ErrNo::ENCODE, e.to_string(), Default::default()),
+        };
+        let url_path = self.get_path_from_object_key(key);
+        let mut query = HashMap::new();
+        query.insert(resource.to_string(), String::new());
+        let mut headers = self.get_common_headers();
+        headers.insert(
+            CONTENT_TYPE,
+            HeaderValue::from_str("application/xml").unwrap(),
+        );
+        headers.insert(
+            HeaderName::from_static("content-md5"),
+            HeaderValue::from_str(&content_md5(body.as_bytes())).unwrap(),
+        );
+        let headers = self.get_headers_with_auth(
+            "put",
+            url_path.as_str(),
+            None,
+            Some(headers),
+            Some(query.clone()),
+        );
+        let resp = Request::put(
+            self.get_full_url_from_path(url_path.as_str()).as_str(),
+            Some(&query),
+            Some(&headers),
+            None,
+            None,
+            Some(Body::from(body)),
+        )
+        .await;
+        self.make_response(resp)
+    }
+
+    /// 查询对象的子资源配置，如`tagging`、`acl`，返回的xml解析为`T`
+    pub(crate) async fn get_object_config<T: serde::de::DeserializeOwned>(
+        &self,
+        key: &str,
+        resource: &str,
+    ) -> Result<T, Response> {
+        let url_path = self.get_path_from_object_key(key);
+        let mut query = HashMap::new();
+        query.insert(resource.to_string(), String::new());
+        let headers =
+            self.get_headers_with_auth("get", url_path.as_str(), None, None, Some(query.clone()));
+        let resp = Request::get(
+            self.get_full_url_from_path(url_path.as_str()).as_str(),
+            Some(&query),
+            Some(&headers),
+        )
+        .await;
+        self.make_xml_response(resp)
+    }
+
+    /// 查询对象的访问控制列表（ACL）
+    /// <https://cloud.tencent.com/document/product/436/7744>
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// if let Ok(policy) = client.get_object_acl("Cargo.toml").await {
+    ///     for grant in policy.grants() {
+    ///         println!("{} {:?}", grant.grantee, grant.permission);
+    ///     }
+    /// }
+    /// };
+    /// ```
+    pub async fn get_object_acl(&self, key: &str) -> Result<acl::AccessControlPolicy, Response> {
+        self.get_object_config(key, "acl").await
+    }
+
+    /// 通过请求体写入对象的访问控制列表（ACL），会覆盖已有的ACL
+    /// <https://cloud.tencent.com/document/product/436/7748>
+    /// # Examples
+    /// ```
+    /// use qcos::acl::{Grantee, Permission};
+    /// use qcos::client::Client;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// if let Ok(mut policy) = client.get_object_acl("Cargo.toml").await {
+    ///     policy.grant(Grantee::sub_user("100000000001", "100000000011"), Permission::READ);
+    ///     let res = client.put_object_acl("Cargo.toml", &policy).await;
+    /// }
+    /// };
+    /// ```
+    pub async fn put_object_acl(&self, key: &str, policy: &acl::AccessControlPolicy) -> Response {
+        self.put_object_config(key, "acl", policy).await
+    }
+
+    /// 下载前通过HEAD请求获取对象大小，并生成分段下载的请求头及查询参数；
+    /// `options`中的条件不满足时直接返回错误，
+    /// 分段请求带上HEAD返回的ETag作为`If-Match`，保证各段数据来自同一个对象
+    async fn prepare_download(
+        &self,
+        key: &str,
+        options: ObjectOptions,
+    ) -> Result<(usize, HeaderMap, HashMap<String, String>), Response> {
+        let url_path = self.get_path_from_object_key(key);
+        let query = options.get_query().clone();
+        let resp = self.head_object_with_options(key, options).await;
+        if resp.error_no != ErrNo::SUCCESS {
+            return Err(resp);
+        }
+        let mut headers = self.get_common_headers();
+        headers.extend(download_headers(&resp.headers)?);
+        let size = match resp.headers.get("content-length").map(|x| x.parse()) {
+            Some(Ok(size)) => size,
+            _ => {
+                return Err(Response::new(
+                    ErrNo::DECODE,
+                    format!("对象{}的content-length缺失或不合法", key),
+                    Default::default(),
+                ))
+            }
+        };
+        let headers = self.get_headers_with_auth(
+            "get",
+            url_path.as_str(),
+            None,
+            Some(headers),
+            Some(query.clone()),
+        );
+        Ok((size, headers, query))
+    }
+
     /// 多线程获取文件二进制数据
     /// <https://cloud.tencent.com/document/product/436/7753>
     /// # 参数
     /// - key: 文件的key，如test/Cargo.lock
+    /// - threads: 下载线程数量，默认5
     ///
     /// # Examples
     /// ```
@@ -1185,23 +2532,34 @@ impl client::Client {
     /// };
     /// ```
     pub async fn get_object_binary(&self, key: &str, threads: Option<u8>) -> Response {
-        let size = self.get_object_size(key).await;
-        if size < 0 {
-            return Response::new(ErrNo::STATUS, String::from("object not exist"), Vec::new());
-        }
-        let size = size as usize;
+        self.get_object_binary_with_options(key, threads, ObjectOptions::new())
+            .await
+    }
+
+    /// 同[`get_object_binary`](Self::get_object_binary)，可传入条件请求头、版本ID等可选项
+    /// - options: 条件请求头、版本ID等可选项，条件不满足时返回`ErrNo::NOT_MODIFIED`或`ErrNo::PRECONDITION_FAILED`
+    pub async fn get_object_binary_with_options(
+        &self,
+        key: &str,
+        threads: Option<u8>,
+        options: ObjectOptions,
+    ) -> Response {
+        let (size, headers, query) = match self.prepare_download(key, options).await {
+            Ok(x) => x,
+            Err(e) => return e,
+        };
         let mut threads = threads.unwrap_or(5) as usize;
         // 小于1KB只启用1个线程
         if size < 1024 {
             threads = 1;
         }
         let url_path = self.get_path_from_object_key(key);
-        let headers = self.get_headers_with_auth("get", url_path.as_str(), None, None, None);
         let url = self.get_full_url_from_path(url_path.as_str());
         let part_size = size / threads;
         let mut handles = Vec::new();
         for i in 0..threads {
             let mut headers = headers.clone();
+            let query = query.clone();
             let url = url.clone();
             let handle = tokio::spawn(async move {
                 // 最后一个线程下载全部
@@ -1212,18 +2570,22 @@ impl client::Client {
                 };
                 let range = format!("bytes={}-{}", i * part_size, range);
                 headers.insert(RANGE, HeaderValue::from_str(&range).unwrap());
-                Request::get(&url, None, Some(&headers)).await
+                Request::get(&url, Some(&query), Some(&headers)).await
             });
             handles.push(handle);
         }
+        let mut verifier = RangeVerifier::new(size, threads, self.get_integrity_check());
         let mut data = Vec::new();
         for handle in handles {
             let response: Response = self.make_response(handle.await.unwrap());
-            if response.error_no != ErrNo::SUCCESS {
-                return response;
+            if let Err(e) = verifier.verify(&response) {
+                return e;
             }
             data.extend(response.result);
         }
+        if let Err(e) = verifier.finish() {
+            return e;
+        }
         Response::data_success(data)
     }
 
@@ -1241,9 +2603,32 @@ impl client::Client {
         key: &str,
         range_start: usize,
         range_end: Option<usize>,
+    ) -> Response {
+        self.get_object_binary_range_with_options(key, range_start, range_end, ObjectOptions::new())
+            .await
+    }
+
+    /// 同[`get_object_binary_range`](Self::get_object_binary_range)，可传入条件请求头、版本ID等可选项
+    /// - options: 条件请求头、版本ID等可选项，条件不满足时返回`ErrNo::NOT_MODIFIED`或`ErrNo::PRECONDITION_FAILED`
+    pub async fn get_object_binary_range_with_options(
+        &self,
+        key: &str,
+        range_start: usize,
+        range_end: Option<usize>,
+        options: ObjectOptions,
     ) -> Response {
         let url_path = self.get_path_from_object_key(key);
-        let mut headers = self.get_headers_with_auth("get", url_path.as_str(), None, None, None);
+        let mut headers = self.get_common_headers();
+        let mut query = HashMap::new();
+        options.extend_headers(&mut headers);
+        query.extend(options.get_query().clone());
+        let mut headers = self.get_headers_with_auth(
+            "get",
+            url_path.as_str(),
+            None,
+            Some(headers),
+            Some(query.clone()),
+        );
         let url = self.get_full_url_from_path(url_path.as_str());
         let start = range_start.to_string();
         let end = match range_end {
@@ -1252,6 +2637,163 @@ impl client::Client {
         };
         let range = format!("bytes={start}-{end}");
         headers.insert(RANGE, HeaderValue::from_str(&range).unwrap());
-        self.make_response(Request::get(&url, None, Some(&headers)).await)
+        self.make_response(Request::get(&url, Some(&query), Some(&headers)).await)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::checksum::{crc64, CRC64_HEADER};
+    use crate::objects::{
+        download_headers, get_max_threads, get_part_size, open_part, ErrNo, PartPlanner,
+        RangeVerifier, Response, PART_DEFAULT_SIZE, PART_MAX_NUM, PART_MAX_SIZE, PART_MIN_SIZE,
+    };
+    use std::path::PathBuf;
+    use std::time::{Duration, Instant};
+    use tokio::io::AsyncReadExt;
+
+    #[test]
+    fn test_download_headers() {
+        let mut head = std::collections::HashMap::new();
+        head.insert("content-length".to_string(), "1024".to_string());
+        assert!(download_headers(&head).unwrap().get("If-Match").is_none());
+        head.insert(
+            "etag".to_string(),
+            "\"e0ad4fa8d1f4a2ab8a8ba1ab0e6b7a4e\"".to_string(),
+        );
+        let headers = download_headers(&head).unwrap();
+        assert_eq!(headers["If-Match"], "\"e0ad4fa8d1f4a2ab8a8ba1ab0e6b7a4e\"");
+        head.insert("etag".to_string(), "\"abc\"\n".to_string());
+        assert_eq!(download_headers(&head).unwrap_err().error_no, ErrNo::PARAM);
+    }
+
+    #[test]
+    fn test_get_part_size() {
+        assert_eq!(get_part_size(100, None).unwrap(), PART_DEFAULT_SIZE);
+        // 文件较大时自动增大分片，分片数量不超过10000
+        let file_size = PART_DEFAULT_SIZE * PART_MAX_NUM * 2;
+        let part_size = get_part_size(file_size, None).unwrap();
+        assert!(file_size.div_ceil(part_size) <= PART_MAX_NUM);
+        assert_eq!(part_size % PART_MIN_SIZE, 0);
+        assert_eq!(
+            get_part_size(100, Some(PART_MIN_SIZE - 1))
+                .unwrap_err()
+                .error_no,
+            ErrNo::PARAM
+        );
+        assert_eq!(
+            get_part_size(100, Some(PART_MAX_SIZE + 1))
+                .unwrap_err()
+                .error_no,
+            ErrNo::PARAM
+        );
+        assert_eq!(
+            get_part_size(PART_MIN_SIZE * PART_MAX_NUM + 1, Some(PART_MIN_SIZE))
+                .unwrap_err()
+                .error_no,
+            ErrNo::PARAM
+        );
+        assert_eq!(
+            get_part_size(PART_MAX_SIZE * PART_MAX_NUM + 1, None)
+                .unwrap_err()
+                .error_no,
+            ErrNo::PARAM
+        );
+        assert_eq!(get_max_threads(None).unwrap(), 20);
+        assert_eq!(get_max_threads(Some(0)).unwrap_err().error_no, ErrNo::PARAM);
+        assert_eq!(
+            get_max_threads(Some(1001)).unwrap_err().error_no,
+            ErrNo::PARAM
+        );
+    }
+
+    #[test]
+    fn test_part_planner() {
+        let file_size = PART_MIN_SIZE * 5 + 100;
+        let planner = PartPlanner::new(file_size, Some(PART_MIN_SIZE * 2), None).unwrap();
+        assert_eq!(planner.next_part_size(0, 1), PART_MIN_SIZE * 2);
+        // 剩余不足一个分片加1MB时全部作为最后一个分片
+        assert_eq!(
+            planner.next_part_size(PART_MIN_SIZE * 2, 2),
+            PART_MIN_SIZE * 2
+        );
+        assert_eq!(
+            planner.next_part_size(PART_MIN_SIZE * 4, 3),
+            PART_MIN_SIZE + 100
+        );
+        let mut planner = PartPlanner::adaptive(file_size * 1000, Some(8)).unwrap();
+        assert_eq!(planner.threads(), 4);
+        // 第一轮完成，吞吐量为每秒8MB，每个连接2MB
+        planner.window_start = Instant::now() - Duration::from_secs(4);
+        for _ in 0..4 {
+            planner.record(PART_MIN_SIZE * 8);
+        }
+        assert_eq!(planner.threads(), 5);
+        assert_eq!(planner.part_size, PART_MIN_SIZE * 10);
+        // 吞吐量下降，并发数反向调整
+        planner.window_start = Instant::now() - Duration::from_secs(100);
+        for _ in 0..5 {
+            planner.record(PART_MIN_SIZE);
+        }
+        assert_eq!(planner.threads(), 4);
+        assert_eq!(planner.part_size, PART_MIN_SIZE);
+        // 最后一个分片号时剩余数据全部上传
+        assert_eq!(
+            planner.next_part_size(file_size * 1000 - PART_MIN_SIZE * 3, PART_MAX_NUM),
+            PART_MIN_SIZE * 3
+        );
+    }
+
+    #[tokio::test]
+    async fn test_open_part() {
+        let file_path = PathBuf::from("Cargo.toml");
+        let content = std::fs::read(&file_path).unwrap();
+        let mut data = Vec::new();
+        let mut reader = open_part(&file_path, 10, 20).await.unwrap();
+        reader.read_to_end(&mut data).await.unwrap();
+        assert_eq!(data, content[10..30].to_vec());
+        // 最后一个分片不足size时读取到文件结尾
+        let mut data = Vec::new();
+        let size = content.len() as u64;
+        let mut reader = open_part(&file_path, size - 5, 20).await.unwrap();
+        reader.read_to_end(&mut data).await.unwrap();
+        assert_eq!(data, content[content.len() - 5..].to_vec());
+    }
+
+    #[test]
+    fn test_range_verifier() {
+        let data = b"0123456789".to_vec();
+        let mut first = Response::data_success(data[..3].to_vec());
+        first
+            .headers
+            .insert(CRC64_HEADER.to_string(), crc64(&data).to_string());
+        let mut verifier = RangeVerifier::new(data.len(), 3, true);
+        assert!(verifier.verify(&first).is_ok());
+        assert!(verifier
+            .verify(&Response::data_success(data[3..6].to_vec()))
+            .is_ok());
+        // 最后一段包含剩余的全部数据
+        assert!(verifier
+            .verify(&Response::data_success(data[6..].to_vec()))
+            .is_ok());
+        assert!(verifier.finish().is_ok());
+
+        // 长度不一致
+        let mut verifier = RangeVerifier::new(data.len(), 3, false);
+        assert_eq!(
+            verifier
+                .verify(&Response::data_success(data[..2].to_vec()))
+                .unwrap_err()
+                .error_no,
+            ErrNo::INTEGRITY
+        );
+
+        // CRC64不一致
+        first
+            .headers
+            .insert(CRC64_HEADER.to_string(), "1".to_string());
+        let mut verifier = RangeVerifier::new(3, 1, true);
+        assert!(verifier.verify(&first).is_ok());
+        assert_eq!(verifier.finish().unwrap_err().error_no, ErrNo::INTEGRITY);
     }
 }
@@ -0,0 +1,268 @@
+//! 对象及存储桶请求的可选项，如条件请求头、版本ID、多AZ配置
+//!
+//! 条件不满足时，接口返回[`ErrNo::NOT_MODIFIED`](crate::request::ErrNo::NOT_MODIFIED)(304)
+//! 或[`ErrNo::PRECONDITION_FAILED`](crate::request::ErrNo::PRECONDITION_FAILED)(412)。
+//! 该映射对所有接口生效，不传可选项的请求返回 304/412 时也不再是`ErrNo::STATUS`
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+
+use crate::bucket::CreateBucketConfiguration;
+use crate::request::{ErrNo, Response};
+use crate::tagging::{Tagging, BUCKET_MAX_TAGS, OBJECT_MAX_TAGS};
+
+/// 对象请求的可选项
+/// # Examples
+/// ```
+/// use qcos::options::ObjectOptions;
+/// let mut options = ObjectOptions::new();
+/// options.insert_if_none_match("\"e0ad4fa8d1f4a2ab8a8ba1ab0e6b7a4e\"").unwrap();
+/// assert!(options.insert_if_match("\"abc\"\n").is_err());
+/// assert_eq!(options.get_headers()["If-None-Match"], "\"e0ad4fa8d1f4a2ab8a8ba1ab0e6b7a4e\"");
+/// ```
+#[derive(Debug, Clone)]
+pub struct ObjectOptions {
+    headers: HashMap<String, String>,
+    query: HashMap<String, String>,
+}
+
+impl Default for ObjectOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 转换为http日期格式，如`Wed, 28 Oct 2020 12:00:00 GMT`
+fn http_date(date: DateTime<Utc>) -> String {
+    date.format("%a, %d %b %Y %H:%M:%S GMT").to_string()
+}
+
+/// 校验`value`可以作为请求头`name`的值，包含换行等控制字符时返回`ErrNo::PARAM`
+fn check_header_value(name: &str, value: &str) -> Result<(), Response> {
+    HeaderValue::from_str(value).map(|_| ()).map_err(|_| {
+        Response::new(
+            ErrNo::PARAM,
+            format!("请求头{}的值不合法: {:?}", name, value),
+            Vec::new(),
+        )
+    })
+}
+
+/// 将请求头写入`headers`，写入`map`时已经过校验，不合法的请求头会被忽略
+fn extend_headers(map: &HashMap<String, String>, headers: &mut HeaderMap) {
+    for (k, v) in map {
+        if let (Ok(name), Ok(value)) = (HeaderName::from_str(k), HeaderValue::from_str(v)) {
+            headers.insert(name, value);
+        }
+    }
+}
+
+impl ObjectOptions {
+    pub fn new() -> ObjectOptions {
+        ObjectOptions {
+            headers: HashMap::new(),
+            query: HashMap::new(),
+        }
+    }
+
+    pub fn get_headers(&self) -> &HashMap<String, String> {
+        &self.headers
+    }
+
+    pub fn get_query(&self) -> &HashMap<String, String> {
+        &self.query
+    }
+
+    /// 操作指定版本的对象，存储桶需开启版本控制，仅对下载及HEAD请求有效
+    pub fn insert_version_id(&mut self, version_id: &str) -> &mut Self {
+        self.query
+            .insert("versionId".to_string(), version_id.to_string());
+        self
+    }
+
+    /// 对象的ETag与`etag`一致时才执行操作，否则返回412；
+    /// `etag`包含换行等控制字符时返回`ErrNo::PARAM`
+    pub fn insert_if_match(&mut self, etag: &str) -> Result<&mut Self, Response> {
+        check_header_value("If-Match", etag)?;
+        self.headers
+            .insert("If-Match".to_string(), etag.to_string());
+        Ok(self)
+    }
+
+    /// 对象的ETag与`etag`不一致时才执行操作，否则GET/HEAD返回304；
+    /// 上传时传入`*`表示对象不存在时才上传；
+    /// `etag`包含换行等控制字符时返回`ErrNo::PARAM`
+    pub fn insert_if_none_match(&mut self, etag: &str) -> Result<&mut Self, Response> {
+        check_header_value("If-None-Match", etag)?;
+        self.headers
+            .insert("If-None-Match".to_string(), etag.to_string());
+        Ok(self)
+    }
+
+    /// 对象在`date`之后被修改过才执行操作，否则返回304
+    pub fn insert_if_modified_since(&mut self, date: DateTime<Utc>) -> &mut Self {
+        self.headers
+            .insert("If-Modified-Since".to_string(), http_date(date));
+        self
+    }
+
+    /// 对象在`date`之后未被修改过才执行操作，否则返回412
+    pub fn insert_if_unmodified_since(&mut self, date: DateTime<Utc>) -> &mut Self {
+        self.headers
+            .insert("If-Unmodified-Since".to_string(), http_date(date));
+        self
+    }
+
+    /// 上传时设置对象的标签，仅对上传有效，标签不合法时返回`ErrNo::PARAM`
+    /// # Examples
+    /// ```
+    /// use qcos::options::ObjectOptions;
+    /// use qcos::tagging::Tagging;
+    /// let mut tagging = Tagging::new();
+    /// tagging.add_tag("team", "storage");
+    /// let mut options = ObjectOptions::new();
+    /// options.insert_tagging(&tagging).unwrap();
+    /// assert_eq!(options.get_headers()["x-cos-tagging"], "team=storage");
+    /// ```
+    pub fn insert_tagging(&mut self, tagging: &Tagging) -> Result<&mut Self, Response> {
+        tagging.validate(OBJECT_MAX_TAGS)?;
+        self.headers
+            .insert("x-cos-tagging".to_string(), tagging.to_header_value());
+        Ok(self)
+    }
+
+    /// 将请求头写入`headers`
+    pub(crate) fn extend_headers(&self, headers: &mut HeaderMap) {
+        extend_headers(&self.headers, headers);
+    }
+}
+
+/// 创建存储桶的可选项
+/// # Examples
+/// ```
+/// use qcos::options::BucketOptions;
+/// use qcos::tagging::Tagging;
+/// let mut tagging = Tagging::new();
+/// tagging.add_tag("env", "test");
+/// let mut options = BucketOptions::new();
+/// options.insert_maz().insert_tagging(&tagging).unwrap();
+/// assert_eq!(options.get_headers()["x-cos-tagging"], "env=test");
+/// assert!(options.get_configuration().is_some());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct BucketOptions {
+    headers: HashMap<String, String>,
+    configuration: Option<CreateBucketConfiguration>,
+}
+
+impl BucketOptions {
+    pub fn new() -> BucketOptions {
+        Self::default()
+    }
+
+    pub fn get_headers(&self) -> &HashMap<String, String> {
+        &self.headers
+    }
+
+    /// 请求体，没有需要写入请求体的配置时为`None`
+    pub fn get_configuration(&self) -> Option<&CreateBucketConfiguration> {
+        self.configuration.as_ref()
+    }
+
+    /// 创建多AZ存储桶，创建后无法修改
+    pub fn insert_maz(&mut self) -> &mut Self {
+        self.configuration = Some(CreateBucketConfiguration::maz());
+        self
+    }
+
+    /// 创建时添加标签，标签不合法时返回`ErrNo::PARAM`
+    pub fn insert_tagging(&mut self, tagging: &Tagging) -> Result<&mut Self, Response> {
+        tagging.validate(BUCKET_MAX_TAGS)?;
+        self.headers
+            .insert("x-cos-tagging".to_string(), tagging.to_header_value());
+        Ok(self)
+    }
+
+    /// 将请求头写入`headers`
+    pub(crate) fn extend_headers(&self, headers: &mut HeaderMap) {
+        extend_headers(&self.headers, headers);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::options::{BucketOptions, ObjectOptions};
+    use crate::request::ErrNo;
+    use crate::tagging::Tagging;
+    use chrono::{TimeZone, Utc};
+    use reqwest::header::HeaderMap;
+
+    #[test]
+    fn test_object_options() {
+        let date = Utc.with_ymd_and_hms(2020, 10, 28, 12, 0, 0).unwrap();
+        let mut options = ObjectOptions::new();
+        options
+            .insert_version_id("MTg0NDUxNTc1NjIzMTQ1MDAwODg")
+            .insert_if_match("\"abc\"")
+            .unwrap()
+            .insert_if_modified_since(date)
+            .insert_if_unmodified_since(date);
+        let mut headers = HeaderMap::new();
+        options.extend_headers(&mut headers);
+        assert_eq!(headers["if-match"], "\"abc\"");
+        assert_eq!(
+            options.get_query()["versionId"],
+            "MTg0NDUxNTc1NjIzMTQ1MDAwODg"
+        );
+        assert_eq!(
+            headers["if-modified-since"],
+            "Wed, 28 Oct 2020 12:00:00 GMT"
+        );
+        assert_eq!(
+            headers["if-unmodified-since"],
+            "Wed, 28 Oct 2020 12:00:00 GMT"
+        );
+    }
+
+    #[test]
+    fn test_invalid_header_value() {
+        let mut options = ObjectOptions::new();
+        let err = options.insert_if_match("\"abc\"\n").unwrap_err();
+        assert_eq!(err.error_no, ErrNo::PARAM);
+        let err = options.insert_if_none_match("\u{7f}").unwrap_err();
+        assert_eq!(err.error_no, ErrNo::PARAM);
+        // 校验失败时不写入
+        assert!(options.get_headers().is_empty());
+        let mut headers = HeaderMap::new();
+        options.extend_headers(&mut headers);
+        assert!(headers.is_empty());
+    }
+
+    #[test]
+    fn test_bucket_options() {
+        let mut options = BucketOptions::new();
+        assert!(options.get_configuration().is_none());
+        let mut tagging = Tagging::new();
+        tagging.add_tag("", "empty");
+        let err = options.insert_tagging(&tagging).unwrap_err();
+        assert_eq!(err.error_no, ErrNo::PARAM);
+        assert!(options.get_headers().is_empty());
+
+        let mut tagging = Tagging::new();
+        tagging.add_tag("team", "a b");
+        options.insert_maz().insert_tagging(&tagging).unwrap();
+        let mut headers = HeaderMap::new();
+        options.extend_headers(&mut headers);
+        assert_eq!(headers["x-cos-tagging"], "team=a%20b");
+        assert_eq!(
+            options
+                .get_configuration()
+                .unwrap()
+                .bucket_az_config
+                .as_deref(),
+            Some("MAZ")
+        );
+    }
+}
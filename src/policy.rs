@@ -0,0 +1,334 @@
+//! 存储桶策略
+//!
+//! 策略以JSON描述，每条语句指定生效方式、被授权者、操作及资源，可附加条件，
+//! 如IP、前缀等，见[文档](https://cloud.tencent.com/document/product/436/12469)。
+use std::collections::{BTreeMap, HashMap};
+
+use reqwest::header::{HeaderName, HeaderValue, CONTENT_TYPE};
+use reqwest::Body;
+use serde::Deserialize;
+
+use crate::checksum::content_md5;
+use crate::client::Client;
+use crate::request::{ErrNo, Request, Response};
+
+/// 匿名用户
+pub const ANYONE: &str = "qcs::cam::anyone:anyone";
+
+/// 策略语言版本
+pub const POLICY_VERSION: &str = "2.0";
+
+/// 条件，如`{"ip_equal": {"qcs:ip": ["10.0.0.0/8"]}}`，外层为条件操作符，内层为条件键
+pub type Condition = BTreeMap<String, BTreeMap<String, serde_json::Value>>;
+
+/// 兼容单个字符串及字符串数组两种写法
+fn one_or_many<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(String),
+        Many(Vec<String>),
+    }
+    Ok(match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(s) => vec![s],
+        OneOrMany::Many(v) => v,
+    })
+}
+
+/// 生效方式
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Copy)]
+pub enum Effect {
+    #[serde(rename = "allow", alias = "Allow")]
+    Allow,
+    #[serde(rename = "deny", alias = "Deny")]
+    Deny,
+}
+
+/// 被授权者
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Default)]
+pub struct Principal {
+    #[serde(rename = "qcs", deserialize_with = "one_or_many", default)]
+    pub qcs: Vec<String>,
+}
+
+impl Principal {
+    /// 匿名用户，即所有人
+    pub fn anyone() -> Self {
+        Self {
+            qcs: vec![ANYONE.to_string()],
+        }
+    }
+
+    /// 主账号`owner_uin`下的子账号`sub_uin`，授权给主账号时两者相同
+    pub fn sub_user(owner_uin: &str, sub_uin: &str) -> Self {
+        Self {
+            qcs: vec![format!("qcs::cam::uin/{}:uin/{}", owner_uin, sub_uin)],
+        }
+    }
+}
+
+/// 策略语句
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct Statement {
+    #[serde(
+        rename = "Sid",
+        alias = "sid",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub sid: Option<String>,
+    #[serde(rename = "Effect", alias = "effect")]
+    pub effect: Effect,
+    #[serde(rename = "Principal", alias = "principal")]
+    pub principal: Principal,
+    /// 操作，如`name/cos:GetObject`，`name/cos:*`表示所有操作
+    #[serde(rename = "Action", alias = "action", deserialize_with = "one_or_many")]
+    pub action: Vec<String>,
+    /// 资源，通过[`Client::policy_resource`]生成
+    #[serde(
+        rename = "Resource",
+        alias = "resource",
+        deserialize_with = "one_or_many"
+    )]
+    pub resource: Vec<String>,
+    #[serde(
+        rename = "Condition",
+        alias = "condition",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub condition: Option<Condition>,
+}
+
+impl Statement {
+    pub fn new(effect: Effect, principal: Principal, action: &[&str], resource: &[&str]) -> Self {
+        Self {
+            sid: None,
+            effect,
+            principal,
+            action: action.iter().map(|x| x.to_string()).collect(),
+            resource: resource.iter().map(|x| x.to_string()).collect(),
+            condition: None,
+        }
+    }
+
+    /// 允许匿名用户读取`resource`，即公有读
+    /// # Examples
+    /// ```
+    /// use qcos::policy::{Effect, Statement};
+    /// let statement = Statement::public_read("qcs::cos:ap-guangzhou:uid/1250000000:examplebucket-1250000000/public/*");
+    /// assert_eq!(statement.effect, Effect::Allow);
+    /// assert!(statement.action.contains(&"name/cos:GetObject".to_string()));
+    /// ```
+    pub fn public_read(resource: &str) -> Self {
+        Self::new(
+            Effect::Allow,
+            Principal::anyone(),
+            &[
+                "name/cos:GetObject",
+                "name/cos:HeadObject",
+                "name/cos:OptionsObject",
+            ],
+            &[resource],
+        )
+    }
+
+    /// 添加条件，同一操作符下的条件键会被覆盖
+    pub fn condition(&mut self, operator: &str, key: &str, value: serde_json::Value) -> &mut Self {
+        self.condition
+            .get_or_insert_with(Default::default)
+            .entry(operator.to_string())
+            .or_default()
+            .insert(key.to_string(), value);
+        self
+    }
+}
+
+/// 存储桶策略
+/// <https://cloud.tencent.com/document/product/436/8282>
+/// ```
+/// use qcos::policy::{PolicyDocument, Statement};
+/// use serde_json::json;
+/// let mut statement = Statement::public_read("qcs::cos:ap-guangzhou:uid/1250000000:examplebucket-1250000000/public/*");
+/// statement.condition("ip_equal", "qcs:ip", json!(["10.0.0.0/8"]));
+/// let mut policy = PolicyDocument::new();
+/// policy.statement(statement);
+/// let s = serde_json::to_string(&policy).unwrap();
+/// assert_eq!(s, r#"{"Statement":[{"Effect":"allow","Principal":{"qcs":["qcs::cam::anyone:anyone"]},"Action":["name/cos:GetObject","name/cos:HeadObject","name/cos:OptionsObject"],"Resource":["qcs::cos:ap-guangzhou:uid/1250000000:examplebucket-1250000000/public/*"],"Condition":{"ip_equal":{"qcs:ip":["10.0.0.0/8"]}}}],"version":"2.0"}"#);
+/// assert_eq!(serde_json::from_str::<PolicyDocument>(&s).unwrap(), policy);
+/// ```
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct PolicyDocument {
+    #[serde(rename = "Statement", alias = "statement", default)]
+    pub statement: Vec<Statement>,
+    #[serde(rename = "version", alias = "Version")]
+    pub version: String,
+}
+
+impl Default for PolicyDocument {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PolicyDocument {
+    pub fn new() -> Self {
+        Self {
+            statement: Vec::new(),
+            version: POLICY_VERSION.to_string(),
+        }
+    }
+
+    /// 添加一条语句
+    pub fn statement(&mut self, statement: Statement) -> &mut Self {
+        self.statement.push(statement);
+        self
+    }
+}
+
+impl Client {
+    /// 生成策略中当前存储桶的资源描述，如`qcs::cos:ap-guangzhou:uid/1250000000:examplebucket-1250000000/public/*`
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// let client = Client::new("foo", "bar", "examplebucket-1250000000", "ap-guangzhou");
+    /// assert_eq!(
+    ///     client.policy_resource("public/*"),
+    ///     "qcs::cos:ap-guangzhou:uid/1250000000:examplebucket-1250000000/public/*"
+    /// );
+    /// ```
+    pub fn policy_resource(&self, key_pattern: &str) -> String {
+        let bucket = self.get_bucket();
+        // 存储桶名称的最后一段为APPID
+        let appid = bucket.rsplit('-').next().unwrap_or_default();
+        format!(
+            "qcs::cos:{}:uid/{}:{}/{}",
+            self.get_region(),
+            appid,
+            bucket,
+            key_pattern.trim_start_matches('/')
+        )
+    }
+
+    /// 设置存储桶策略，会覆盖已有的策略
+    /// <https://cloud.tencent.com/document/product/436/8282>
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// use qcos::policy::{PolicyDocument, Statement};
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let mut policy = PolicyDocument::new();
+    /// policy.statement(Statement::public_read(&client.policy_resource("public/*")));
+    /// let res = client.put_bucket_policy(&policy).await;
+    /// assert!(res.error_message.contains("403"));
+    /// };
+    /// ```
+    pub async fn put_bucket_policy(&self, policy: &PolicyDocument) -> Response {
+        let body = match serde_json::to_string(policy) {
+            Ok(s) => s,
+            Err(e) => return Response::new(ErrNo::ENCODE, e.to_string(), Default::default()),
+        };
+        let mut query = HashMap::new();
+        query.insert("policy".to_string(), String::new());
+        let mut headers = self.get_common_headers();
+        headers.insert(
+            CONTENT_TYPE,
+            HeaderValue::from_str("application/json").unwrap(),
+        );
+        headers.insert(
+            HeaderName::from_static("content-md5"),
+            HeaderValue::from_str(&content_md5(body.as_bytes())).unwrap(),
+        );
+        let headers =
+            self.get_headers_with_auth("put", "/", None, Some(headers), Some(query.clone()));
+        let resp = Request::put(
+            self.get_full_url_from_path("/").as_str(),
+            Some(&query),
+            Some(&headers),
+            None,
+            None,
+            Some(Body::from(body)),
+        )
+        .await;
+        self.make_response(resp)
+    }
+
+    /// 查询存储桶策略，未设置时返回404
+    /// <https://cloud.tencent.com/document/product/436/8276>
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// if let Ok(policy) = client.get_bucket_policy().await {
+    ///     println!("{:?}", policy.statement);
+    /// }
+    /// };
+    /// ```
+    pub async fn get_bucket_policy(&self) -> Result<PolicyDocument, Response> {
+        let mut query = HashMap::new();
+        query.insert("policy".to_string(), String::new());
+        let headers = self.get_headers_with_auth("get", "/", None, None, Some(query.clone()));
+        let resp = Request::get(
+            self.get_full_url_from_path("/").as_str(),
+            Some(&query),
+            Some(&headers),
+        )
+        .await;
+        let resp = self.make_response(resp);
+        if resp.error_no != ErrNo::SUCCESS {
+            return Err(resp);
+        }
+        serde_json::from_slice(&resp.result)
+            .map_err(|e| Response::new(ErrNo::DECODE, e.to_string(), resp.result.clone()))
+    }
+
+    /// 删除存储桶策略
+    /// <https://cloud.tencent.com/document/product/436/8285>
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let res = client.delete_bucket_policy().await;
+    /// assert!(res.error_message.contains("403"));
+    /// };
+    /// ```
+    pub async fn delete_bucket_policy(&self) -> Response {
+        self.delete_bucket_config("policy").await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::policy::{Effect, PolicyDocument, Principal};
+
+    #[test]
+    fn test_policy_document() {
+        // 查询返回的键可能为小写，操作及资源可能为单个字符串
+        let s = r#"{
+            "statement": [{
+                "principal": {"qcs": "qcs::cam::uin/100000000001:uin/100000000011"},
+                "effect": "Deny",
+                "action": "name/cos:*",
+                "resource": ["qcs::cos:ap-guangzhou:uid/1250000000:examplebucket-1250000000/*"],
+                "condition": {"ip_not_equal": {"qcs:ip": "10.0.0.1"}}
+            }],
+            "version": "2.0"
+        }"#;
+        let policy: PolicyDocument = serde_json::from_str(s).unwrap();
+        let statement = &policy.statement[0];
+        assert_eq!(statement.effect, Effect::Deny);
+        assert_eq!(
+            statement.principal,
+            Principal::sub_user("100000000001", "100000000011")
+        );
+        assert_eq!(statement.action, vec!["name/cos:*".to_string()]);
+        assert_eq!(statement.resource.len(), 1);
+        assert_eq!(
+            statement.condition.as_ref().unwrap()["ip_not_equal"]["qcs:ip"],
+            "10.0.0.1"
+        );
+    }
+}
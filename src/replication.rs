@@ -0,0 +1,257 @@
+//! 跨地域复制
+//!
+//! 跨地域复制将源存储桶中新增的对象按前缀异步复制到其他地域的目标存储桶，
+//! 源存储桶及目标存储桶都需要开启版本控制。
+use std::collections::HashSet;
+
+use crate::client::Client;
+use crate::lifecycle::RuleStatus;
+use crate::objects::StorageClassEnum;
+use crate::request::{ErrNo, Response};
+
+/// 复制的目标存储桶
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct ReplicationDestination {
+    /// 格式为`qcs::cos:<Region>::<BucketName-APPID>`
+    #[serde(rename = "Bucket")]
+    pub bucket: String,
+    /// 副本的存储类型，不指定时与源对象相同
+    #[serde(
+        rename = "StorageClass",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub storage_class: Option<StorageClassEnum>,
+}
+
+impl ReplicationDestination {
+    /// 复制到`region`地域的`bucket`存储桶
+    pub fn new(region: &str, bucket: &str) -> Self {
+        Self {
+            bucket: format!("qcs::cos:{}::{}", region, bucket),
+            storage_class: None,
+        }
+    }
+}
+
+/// 复制规则
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct ReplicationRule {
+    #[serde(rename = "Status")]
+    pub status: RuleStatus,
+    #[serde(rename = "ID", default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// 对象键前缀，为空表示整个存储桶，不同规则的前缀不能重叠
+    #[serde(rename = "Prefix", default)]
+    pub prefix: String,
+    #[serde(rename = "Destination")]
+    pub destination: ReplicationDestination,
+}
+
+impl ReplicationRule {
+    /// 创建启用状态的规则，将前缀为`prefix`的对象复制到`destination`
+    pub fn new(id: &str, prefix: &str, destination: ReplicationDestination) -> Self {
+        Self {
+            status: RuleStatus::Enabled,
+            id: Some(id.to_string()),
+            prefix: prefix.to_string(),
+            destination,
+        }
+    }
+
+    /// 指定副本的存储类型
+    pub fn storage_class(&mut self, storage_class: StorageClassEnum) -> &mut Self {
+        self.destination.storage_class = Some(storage_class);
+        self
+    }
+}
+
+/// 存储桶的跨地域复制配置
+/// <https://cloud.tencent.com/document/product/436/19223>
+/// ```
+/// use qcos::objects::StorageClassEnum;
+/// use qcos::replication::{ReplicationConfiguration, ReplicationDestination, ReplicationRule};
+/// use quick_xml::de::from_str;
+/// use quick_xml::se::to_string;
+/// let mut rule = ReplicationRule::new(
+///     "backup",
+///     "data/",
+///     ReplicationDestination::new("ap-chengdu", "backup-1250000000"),
+/// );
+/// rule.storage_class(StorageClassEnum::StandardIa);
+/// let mut config = ReplicationConfiguration::new("qcs::cam::uin/100000000001:uin/100000000001");
+/// config.rule(rule);
+/// let s = to_string(&config).unwrap();
+/// assert_eq!(s, "<ReplicationConfiguration><Role>qcs::cam::uin/100000000001:uin/100000000001</Role><Rule><Status>Enabled</Status><ID>backup</ID><Prefix>data/</Prefix><Destination><Bucket>qcs::cos:ap-chengdu::backup-1250000000</Bucket><StorageClass>STANDARD_IA</StorageClass></Destination></Rule></ReplicationConfiguration>");
+/// assert_eq!(from_str::<ReplicationConfiguration>(&s).unwrap(), config);
+/// ```
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct ReplicationConfiguration {
+    /// 发起复制的身份，格式为`qcs::cam::uin/<OwnerUin>:uin/<SubUin>`
+    #[serde(rename = "Role")]
+    pub role: String,
+    #[serde(rename = "Rule", default)]
+    pub rules: Vec<ReplicationRule>,
+}
+
+impl ReplicationConfiguration {
+    pub fn new(role: &str) -> Self {
+        Self {
+            role: role.to_string(),
+            rules: Vec::new(),
+        }
+    }
+
+    /// 添加一条规则
+    pub fn rule(&mut self, rule: ReplicationRule) -> &mut Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// 校验身份及规则不为空且规则ID不重复，不满足时返回`ErrNo::PARAM`
+    pub fn validate(&self) -> Result<(), Response> {
+        let error = |message: String| Err(Response::new(ErrNo::PARAM, message, Vec::new()));
+        if self.role.is_empty() {
+            return error("复制身份不能为空".to_string());
+        }
+        if self.rules.is_empty() {
+            return error("复制规则不能为空".to_string());
+        }
+        let mut ids = HashSet::new();
+        for rule in &self.rules {
+            if rule.destination.bucket.is_empty() {
+                return error("复制规则的目标存储桶不能为空".to_string());
+            }
+            if let Some(id) = &rule.id {
+                if !ids.insert(id) {
+                    return error(format!("复制规则ID{}重复", id));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Client {
+    /// 设置存储桶的跨地域复制规则，会覆盖已有的规则
+    ///
+    /// 提交前会查询源存储桶的版本控制状态，未开启时返回`ErrNo::PARAM`
+    /// <https://cloud.tencent.com/document/product/436/19223>
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// use qcos::replication::{ReplicationConfiguration, ReplicationDestination, ReplicationRule};
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let mut config = ReplicationConfiguration::new("qcs::cam::uin/100000000001:uin/100000000001");
+    /// config.rule(ReplicationRule::new(
+    ///     "all",
+    ///     "",
+    ///     ReplicationDestination::new("ap-chengdu", "backup-1250000000"),
+    /// ));
+    /// let res = client.put_bucket_replication(&config).await;
+    /// assert!(res.error_message.contains("403"));
+    /// };
+    /// ```
+    pub async fn put_bucket_replication(&self, config: &ReplicationConfiguration) -> Response {
+        if let Err(e) = config.validate() {
+            return e;
+        }
+        match self.get_bucket_versioning().await {
+            Ok(versioning) if versioning.is_enabled() => {}
+            Ok(_) => {
+                return Response::new(
+                    ErrNo::PARAM,
+                    "源存储桶未开启版本控制，无法设置跨地域复制".to_string(),
+                    Default::default(),
+                )
+            }
+            Err(e) => return e,
+        }
+        self.put_bucket_config("replication", config).await
+    }
+
+    /// 查询存储桶的跨地域复制规则，未设置时返回404
+    /// <https://cloud.tencent.com/document/product/436/19222>
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// if let Ok(config) = client.get_bucket_replication().await {
+    ///     println!("{:?}", config.rules);
+    /// }
+    /// };
+    /// ```
+    pub async fn get_bucket_replication(&self) -> Result<ReplicationConfiguration, Response> {
+        self.get_bucket_config("replication").await
+    }
+
+    /// 删除存储桶的跨地域复制规则
+    /// <https://cloud.tencent.com/document/product/436/19221>
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let res = client.delete_bucket_replication().await;
+    /// assert!(res.error_message.contains("403"));
+    /// };
+    /// ```
+    pub async fn delete_bucket_replication(&self) -> Response {
+        self.delete_bucket_config("replication").await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::lifecycle::RuleStatus;
+    use crate::objects::StorageClassEnum;
+    use crate::replication::{ReplicationConfiguration, ReplicationDestination, ReplicationRule};
+    use crate::request::ErrNo;
+
+    #[test]
+    fn test_replication_configuration() {
+        let xml = r#"<ReplicationConfiguration>
+            <Role>qcs::cam::uin/100000000001:uin/100000000011</Role>
+            <Rule>
+                <Status>Disabled</Status>
+                <ID>logs</ID>
+                <Prefix>logs/</Prefix>
+                <Destination>
+                    <Bucket>qcs::cos:ap-chengdu::backup-1250000000</Bucket>
+                    <StorageClass>ARCHIVE</StorageClass>
+                </Destination>
+            </Rule>
+            <Rule>
+                <Status>Enabled</Status>
+                <Prefix>data/</Prefix>
+                <Destination>
+                    <Bucket>qcs::cos:ap-shanghai::backup-1250000000</Bucket>
+                </Destination>
+            </Rule>
+        </ReplicationConfiguration>"#;
+        let mut config: ReplicationConfiguration = quick_xml::de::from_str(xml).unwrap();
+        assert!(config.validate().is_ok());
+        assert_eq!(config.rules[0].status, RuleStatus::Disabled);
+        assert_eq!(
+            config.rules[0].destination.storage_class,
+            Some(StorageClassEnum::ARCHIVE)
+        );
+        assert_eq!(config.rules[1].id, None);
+        assert_eq!(
+            config.rules[1].destination,
+            ReplicationDestination::new("ap-shanghai", "backup-1250000000")
+        );
+
+        config.rule(ReplicationRule::new(
+            "logs",
+            "other/",
+            ReplicationDestination::new("ap-chengdu", "backup-1250000000"),
+        ));
+        assert_eq!(config.validate().unwrap_err().error_no, ErrNo::PARAM);
+        assert!(ReplicationConfiguration::new("qcs::cam::uin/1:uin/1")
+            .validate()
+            .is_err());
+    }
+}
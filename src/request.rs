@@ -1,4 +1,6 @@
 //! 请求封装
+use crate::objects::StorageClassEnum;
+use chrono::{DateTime, Utc};
 use std::collections::HashMap;
 use std::fmt::Display;
 
@@ -44,7 +46,424 @@ pub struct Part {
     pub etag: String,
 }
 
+/// 已上传的分片信息
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct UploadedPart {
+    #[serde(rename = "PartNumber")]
+    pub part_number: u64,
+    #[serde(rename = "ETag")]
+    pub etag: String,
+    /// 分片大小(bytes)
+    #[serde(rename = "Size")]
+    pub size: u64,
+    #[serde(rename = "LastModified")]
+    pub last_modified: DateTime<Utc>,
+}
+
+impl From<UploadedPart> for Part {
+    fn from(value: UploadedPart) -> Self {
+        Part {
+            part_number: value.part_number,
+            etag: value.etag,
+        }
+    }
+}
+
+/// 查询已上传分片的结果
+/// <https://cloud.tencent.com/document/product/436/7747>
+/// ```
+/// use qcos::request::{ListPartsResult, Part};
+/// use quick_xml::de::from_str;
+/// let s = r#"<ListPartsResult><Bucket>examplebucket-1250000000</Bucket><Encoding-type/><Key>exampleobject</Key><UploadId>1585130821cbb7df1d11846c073ad648e8f33b087cec2381df437acdc833cf654b9ecc6361</UploadId><Initiator><ID>qcs::cam::uin/100000000001:uin/100000000001</ID><DisplayName>100000000001</DisplayName></Initiator><Owner><ID>qcs::cam::uin/100000000001:uin/100000000001</ID><DisplayName>100000000001</DisplayName></Owner><PartNumberMarker>0</PartNumberMarker><Part><PartNumber>1</PartNumber><LastModified>2020-03-25T10:13:53.000Z</LastModified><ETag>"e1e5b4965bc7d30880ed6d226f78a5390f27b4de"</ETag><Size>1048576</Size></Part><Part><PartNumber>2</PartNumber><LastModified>2020-03-25T10:14:04.000Z</LastModified><ETag>"ee7a3a5a6a9b6ff2d9d3a8d3d6e7b0e7c1f8a2a8"</ETag><Size>1024</Size></Part><NextPartNumberMarker>2</NextPartNumberMarker><StorageClass>STANDARD</StorageClass><MaxParts>2</MaxParts><IsTruncated>true</IsTruncated></ListPartsResult>"#;
+/// let res: ListPartsResult = from_str(s).unwrap();
+/// assert!(res.is_truncated);
+/// assert_eq!(res.next_part_number_marker, 2);
+/// assert_eq!(res.part.len(), 2);
+/// assert_eq!(res.part[1].size, 1024);
+/// let part: Part = res.part[0].clone().into();
+/// assert_eq!(part.etag, r#""e1e5b4965bc7d30880ed6d226f78a5390f27b4de""#);
+/// ```
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+pub struct ListPartsResult {
+    #[serde(rename = "Bucket")]
+    pub bucket: String,
+    #[serde(rename = "Key")]
+    pub key: String,
+    #[serde(rename = "UploadId")]
+    pub upload_id: String,
+    #[serde(rename = "Initiator", default)]
+    pub initiator: Owner,
+    #[serde(rename = "Owner", default)]
+    pub owner: Owner,
+    #[serde(rename = "StorageClass", default)]
+    pub storage_class: StorageClassEnum,
+    #[serde(rename = "PartNumberMarker", default)]
+    pub part_number_marker: u64,
+    #[serde(rename = "MaxParts", default)]
+    pub max_parts: u64,
+    /// 是否还有未返回的分片，为`true`时使用`next_part_number_marker`继续查询
+    #[serde(rename = "IsTruncated", default)]
+    pub is_truncated: bool,
+    #[serde(rename = "NextPartNumberMarker", default)]
+    pub next_part_number_marker: u64,
+    #[serde(rename = "Part", default)]
+    pub part: Vec<UploadedPart>,
+}
+
+/// 所有者或发起者信息
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Default)]
+pub struct Owner {
+    #[serde(rename = "ID")]
+    pub id: String,
+    #[serde(rename = "DisplayName", default)]
+    pub display_name: String,
+}
+
+/// 进行中的分块上传
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+pub struct Upload {
+    #[serde(rename = "Key")]
+    pub key: String,
+    #[serde(rename = "UploadId")]
+    pub upload_id: String,
+    #[serde(rename = "StorageClass", default)]
+    pub storage_class: StorageClassEnum,
+    #[serde(rename = "Initiator", default)]
+    pub initiator: Owner,
+    #[serde(rename = "Owner", default)]
+    pub owner: Owner,
+    /// 分块上传的初始化时间
+    #[serde(rename = "Initiated")]
+    pub initiated: DateTime<Utc>,
+}
+
+/// 查询分块上传的结果
+/// <https://cloud.tencent.com/document/product/436/7736>
+/// ```
+/// use qcos::request::ListMultipartUploadsResult;
+/// use quick_xml::de::from_str;
+/// let s = r#"<ListMultipartUploadsResult><Bucket>examplebucket-1250000000</Bucket><KeyMarker/><UploadIdMarker/><MaxUploads>1000</MaxUploads><Prefix/><IsTruncated>true</IsTruncated><NextKeyMarker>exampleobject</NextKeyMarker><NextUploadIdMarker>1585130821cbb7df1d11846c073ad648e8f33b087cec2381df437acdc833cf654b9ecc6361</NextUploadIdMarker><Upload><Key>exampleobject</Key><UploadId>1585130821cbb7df1d11846c073ad648e8f33b087cec2381df437acdc833cf654b9ecc6361</UploadId><StorageClass>STANDARD</StorageClass><Initiator><ID>qcs::cam::uin/100000000001:uin/100000000001</ID><DisplayName>100000000001</DisplayName></Initiator><Owner><ID>qcs::cam::uin/100000000001:uin/100000000001</ID><DisplayName>100000000001</DisplayName></Owner><Initiated>2020-03-25T10:07:01.000Z</Initiated></Upload></ListMultipartUploadsResult>"#;
+/// let res: ListMultipartUploadsResult = from_str(s).unwrap();
+/// assert!(res.is_truncated);
+/// assert_eq!(res.next_key_marker, "exampleobject");
+/// assert_eq!(res.upload.len(), 1);
+/// assert_eq!(res.upload[0].key, "exampleobject");
+/// assert_eq!(res.upload[0].owner.display_name, "100000000001");
+/// assert_eq!(res.upload[0].initiated.to_rfc3339(), "2020-03-25T10:07:01+00:00");
+/// ```
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+pub struct ListMultipartUploadsResult {
+    #[serde(rename = "Bucket")]
+    pub bucket: String,
+    #[serde(rename = "Prefix", default)]
+    pub prefix: String,
+    #[serde(rename = "KeyMarker", default)]
+    pub key_marker: String,
+    #[serde(rename = "UploadIdMarker", default)]
+    pub upload_id_marker: String,
+    #[serde(rename = "MaxUploads", default)]
+    pub max_uploads: u64,
+    /// 是否还有未返回的结果，为`true`时使用`next_key_marker`及`next_upload_id_marker`继续查询
+    #[serde(rename = "IsTruncated", default)]
+    pub is_truncated: bool,
+    #[serde(rename = "NextKeyMarker", default)]
+    pub next_key_marker: String,
+    #[serde(rename = "NextUploadIdMarker", default)]
+    pub next_upload_id_marker: String,
+    #[serde(rename = "Upload", default)]
+    pub upload: Vec<Upload>,
+}
+
+/// 复制对象的结果
+/// <https://cloud.tencent.com/document/product/436/10881>
+/// ```
+/// use qcos::request::CopyObjectResult;
+/// use quick_xml::de::from_str;
+/// let s = r#"<CopyObjectResult><ETag>"ee8de918d05640145b18f70f4c3aa602"</ETag><CRC64>16749565679157681890</CRC64><LastModified>2020-03-25T10:07:01Z</LastModified></CopyObjectResult>"#;
+/// let res: CopyObjectResult = from_str(s).unwrap();
+/// assert_eq!(res.etag, r#""ee8de918d05640145b18f70f4c3aa602""#);
+/// assert_eq!(res.version_id, None);
+/// ```
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct CopyObjectResult {
+    #[serde(rename = "ETag")]
+    pub etag: String,
+    #[serde(rename = "LastModified")]
+    pub last_modified: DateTime<Utc>,
+    /// 目标对象的版本ID，存储桶开启版本控制时才有值，取自响应头`x-cos-version-id`
+    #[serde(skip)]
+    pub version_id: Option<String>,
+}
+
+/// 复制分片的结果
+/// <https://cloud.tencent.com/document/product/436/8287>
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct CopyPartResult {
+    #[serde(rename = "ETag")]
+    pub etag: String,
+    #[serde(rename = "LastModified")]
+    pub last_modified: DateTime<Utc>,
+}
+
+/// 存储桶中的一个对象
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct ObjectSummary {
+    #[serde(rename = "Key")]
+    pub key: String,
+    #[serde(rename = "LastModified")]
+    pub last_modified: DateTime<Utc>,
+    #[serde(rename = "ETag", default)]
+    pub etag: String,
+    #[serde(rename = "Size", default)]
+    pub size: u64,
+    #[serde(rename = "StorageClass", default)]
+    pub storage_class: StorageClassEnum,
+    #[serde(rename = "Owner", default)]
+    pub owner: Owner,
+}
+
+/// 按`delimiter`分组后的公共前缀
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct CommonPrefix {
+    #[serde(rename = "Prefix")]
+    pub prefix: String,
+}
+
+/// 查询对象列表的结果，可用于解析[`list_objects()`]的返回
+/// <https://cloud.tencent.com/document/product/436/7734>
+/// ```
+/// use qcos::request::ListBucketResult;
+/// use quick_xml::de::from_str;
+/// let s = r#"<ListBucketResult><Name>examplebucket-1250000000</Name><Prefix/><Marker/><MaxKeys>1000</MaxKeys><Delimiter>/</Delimiter><IsTruncated>true</IsTruncated><NextMarker>example.txt</NextMarker><Contents><Key>example.txt</Key><LastModified>2020-03-25T10:07:01.000Z</LastModified><ETag>"ee8de918d05640145b18f70f4c3aa602"</ETag><Size>17</Size><Owner><ID>1250000000</ID><DisplayName>1250000000</DisplayName></Owner><StorageClass>STANDARD</StorageClass></Contents><CommonPrefixes><Prefix>doc/</Prefix></CommonPrefixes></ListBucketResult>"#;
+/// let res: ListBucketResult = from_str(s).unwrap();
+/// assert!(res.is_truncated);
+/// assert_eq!(res.contents[0].size, 17);
+/// assert_eq!(res.common_prefixes[0].prefix, "doc/");
+/// assert_eq!(res.contents[0].storage_class, qcos::objects::StorageClassEnum::STANDARD);
+/// ```
+///
+/// [`list_objects()`]: crate::client::Client::list_objects
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct ListBucketResult {
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "Prefix", default)]
+    pub prefix: String,
+    #[serde(rename = "Marker", default)]
+    pub marker: String,
+    #[serde(rename = "MaxKeys", default)]
+    pub max_keys: u64,
+    #[serde(rename = "Delimiter", default)]
+    pub delimiter: String,
+    /// 是否还有未返回的结果，为`true`时使用`next_marker`继续查询
+    #[serde(rename = "IsTruncated", default)]
+    pub is_truncated: bool,
+    #[serde(rename = "NextMarker", default)]
+    pub next_marker: String,
+    #[serde(rename = "Contents", default)]
+    pub contents: Vec<ObjectSummary>,
+    #[serde(rename = "CommonPrefixes", default)]
+    pub common_prefixes: Vec<CommonPrefix>,
+}
+
+/// 存储桶的版本控制状态
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Copy)]
+pub enum VersioningStatus {
+    /// 已开启
+    Enabled,
+    /// 已暂停
+    Suspended,
+}
+
+/// 存储桶的版本控制配置
+/// <https://cloud.tencent.com/document/product/436/19888>
+/// ```
+/// use qcos::request::{VersioningConfiguration, VersioningStatus};
+/// use quick_xml::de::from_str;
+/// use quick_xml::se::to_string;
+/// let res: VersioningConfiguration = from_str("<VersioningConfiguration/>").unwrap();
+/// assert_eq!(res.status, None);
+/// assert!(!res.is_enabled());
+/// let config = VersioningConfiguration { status: Some(VersioningStatus::Enabled) };
+/// let s = to_string(&config).unwrap();
+/// assert_eq!(s, "<VersioningConfiguration><Status>Enabled</Status></VersioningConfiguration>");
+/// assert_eq!(from_str::<VersioningConfiguration>(&s).unwrap(), config);
+/// ```
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Default)]
+pub struct VersioningConfiguration {
+    /// 从未开启过版本控制时为`None`
+    #[serde(rename = "Status", default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<VersioningStatus>,
+}
+
+impl VersioningConfiguration {
+    /// 版本控制是否处于开启状态
+    pub fn is_enabled(&self) -> bool {
+        self.status == Some(VersioningStatus::Enabled)
+    }
+}
+
+/// 对象的一个历史版本
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct Version {
+    #[serde(rename = "Key")]
+    pub key: String,
+    #[serde(rename = "VersionId")]
+    pub version_id: String,
+    /// 是否为最新版本
+    #[serde(rename = "IsLatest", default)]
+    pub is_latest: bool,
+    #[serde(rename = "LastModified")]
+    pub last_modified: DateTime<Utc>,
+    #[serde(rename = "ETag", default)]
+    pub etag: String,
+    #[serde(rename = "Size", default)]
+    pub size: u64,
+    #[serde(rename = "StorageClass", default)]
+    pub storage_class: StorageClassEnum,
+    #[serde(rename = "Owner", default)]
+    pub owner: Owner,
+}
+
+/// 删除标记，开启版本控制后删除对象不会删除数据，而是生成一个删除标记作为最新版本
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct DeleteMarker {
+    #[serde(rename = "Key")]
+    pub key: String,
+    #[serde(rename = "VersionId")]
+    pub version_id: String,
+    #[serde(rename = "IsLatest", default)]
+    pub is_latest: bool,
+    #[serde(rename = "LastModified")]
+    pub last_modified: DateTime<Utc>,
+    #[serde(rename = "Owner", default)]
+    pub owner: Owner,
+}
+
+/// 查询对象历史版本的结果
+/// <https://cloud.tencent.com/document/product/436/35521>
+/// ```
+/// use qcos::request::ListVersionsResult;
+/// use quick_xml::de::from_str;
+/// let s = r#"<ListVersionsResult><Name>examplebucket-1250000000</Name><Prefix/><KeyMarker/><VersionIdMarker/><MaxKeys>1000</MaxKeys><IsTruncated>false</IsTruncated><DeleteMarker><Key>example.txt</Key><VersionId>MTg0NDUxNjQ1NTQ3NjUwNzQ5NDM</VersionId><IsLatest>true</IsLatest><LastModified>2020-03-25T10:07:02.000Z</LastModified><Owner><ID>1250000000</ID><DisplayName>1250000000</DisplayName></Owner></DeleteMarker><Version><Key>example.txt</Key><VersionId>MTg0NDUxNjQ1NTQ3NjUwNzQ5NDU</VersionId><IsLatest>false</IsLatest><LastModified>2020-03-25T10:07:01.000Z</LastModified><ETag>"ee8de918d05640145b18f70f4c3aa602"</ETag><Size>17</Size><StorageClass>STANDARD</StorageClass><Owner><ID>1250000000</ID><DisplayName>1250000000</DisplayName></Owner></Version><DeleteMarker><Key>other.txt</Key><VersionId>MTg0NDUxNjQ1NTQ3NjUwNzQ5NDY</VersionId><IsLatest>true</IsLatest><LastModified>2020-03-25T10:07:03.000Z</LastModified></DeleteMarker></ListVersionsResult>"#;
+/// let res: ListVersionsResult = from_str(s).unwrap();
+/// assert!(!res.is_truncated);
+/// assert_eq!(res.version.len(), 1);
+/// assert_eq!(res.version[0].size, 17);
+/// assert_eq!(res.delete_marker.len(), 2);
+/// assert!(res.delete_marker[0].is_latest);
+/// ```
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct ListVersionsResult {
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "Prefix", default)]
+    pub prefix: String,
+    #[serde(rename = "KeyMarker", default)]
+    pub key_marker: String,
+    #[serde(rename = "VersionIdMarker", default)]
+    pub version_id_marker: String,
+    #[serde(rename = "MaxKeys", default)]
+    pub max_keys: u64,
+    /// 是否还有未返回的结果，为`true`时使用`next_key_marker`及`next_version_id_marker`继续查询
+    #[serde(rename = "IsTruncated", default)]
+    pub is_truncated: bool,
+    #[serde(rename = "NextKeyMarker", default)]
+    pub next_key_marker: String,
+    #[serde(rename = "NextVersionIdMarker", default)]
+    pub next_version_id_marker: String,
+    #[serde(rename = "Version", default)]
+    pub version: Vec<Version>,
+    #[serde(rename = "DeleteMarker", default)]
+    pub delete_marker: Vec<DeleteMarker>,
+}
+
+/// 批量删除中的一个对象
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct ObjectIdentifier {
+    #[serde(rename = "Key")]
+    pub key: String,
+    /// 为`None`时删除最新版本，开启版本控制时会产生删除标记
+    #[serde(rename = "VersionId", default, skip_serializing_if = "Option::is_none")]
+    pub version_id: Option<String>,
+}
+
+impl ObjectIdentifier {
+    /// 对象的指定版本，`version_id`为空表示最新版本
+    pub fn new(key: &str, version_id: &str) -> Self {
+        Self {
+            key: key.to_string(),
+            version_id: (!version_id.is_empty()).then(|| version_id.to_string()),
+        }
+    }
+}
+
+/// 批量删除的请求体
+/// <https://cloud.tencent.com/document/product/436/8289>
+/// ```
+/// use qcos::request::{Delete, ObjectIdentifier};
+/// use quick_xml::se::to_string;
+/// let delete = Delete {
+///     quiet: true,
+///     object: vec![ObjectIdentifier::new("a.txt", ""), ObjectIdentifier::new("b.txt", "MTg0NDUxNTc1NjIzMTQ1MDAwODg")],
+/// };
+/// assert_eq!(to_string(&delete).unwrap(), "<Delete><Quiet>true</Quiet><Object><Key>a.txt</Key></Object><Object><Key>b.txt</Key><VersionId>MTg0NDUxNTc1NjIzMTQ1MDAwODg</VersionId></Object></Delete>");
+/// ```
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct Delete {
+    /// 为`true`时只返回删除失败的对象
+    #[serde(rename = "Quiet")]
+    pub quiet: bool,
+    #[serde(rename = "Object", default)]
+    pub object: Vec<ObjectIdentifier>,
+}
+
+/// 批量删除中删除成功的对象
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct DeletedObject {
+    #[serde(rename = "Key")]
+    pub key: String,
+    #[serde(rename = "VersionId", default)]
+    pub version_id: Option<String>,
+    /// 是否产生或删除了删除标记
+    #[serde(rename = "DeleteMarker", default)]
+    pub delete_marker: Option<bool>,
+    #[serde(rename = "DeleteMarkerVersionId", default)]
+    pub delete_marker_version_id: Option<String>,
+}
+
+/// 批量删除中删除失败的对象
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct DeleteError {
+    #[serde(rename = "Key")]
+    pub key: String,
+    #[serde(rename = "VersionId", default)]
+    pub version_id: Option<String>,
+    #[serde(rename = "Code", default)]
+    pub code: String,
+    #[serde(rename = "Message", default)]
+    pub message: String,
+}
+
+/// 批量删除的结果
+/// ```
+/// use qcos::request::DeleteResult;
+/// use quick_xml::de::from_str;
+/// let s = "<DeleteResult><Deleted><Key>a.txt</Key><DeleteMarker>true</DeleteMarker><DeleteMarkerVersionId>MTg0NDUxNTc1NjIzMTQ1MDAwODg</DeleteMarkerVersionId></Deleted><Error><Key>b.txt</Key><Code>AccessDenied</Code><Message>Access Denied</Message></Error></DeleteResult>";
+/// let res: DeleteResult = from_str(s).unwrap();
+/// assert_eq!(res.deleted[0].delete_marker, Some(true));
+/// assert_eq!(res.error[0].code, "AccessDenied");
+/// ```
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Default)]
+pub struct DeleteResult {
+    #[serde(rename = "Deleted", default)]
+    pub deleted: Vec<DeletedObject>,
+    #[serde(rename = "Error", default)]
+    pub error: Vec<DeleteError>,
+}
+
 /// 错误码
+#[allow(non_camel_case_types)]
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum ErrNo {
     /// 操作成功
@@ -57,10 +476,39 @@ pub enum ErrNo {
     DECODE = 10002,
     /// 连接相关错误
     CONNECT = 10003,
+    /// 条件请求时对象未被修改(304)
+    NOT_MODIFIED = 10004,
+    /// 条件请求时前置条件不满足(412)
+    PRECONDITION_FAILED = 10005,
     /// 编码相关错误
     ENCODE = 20001,
     /// IO错误
     IO = 20002,
+    /// 参数错误
+    PARAM = 20003,
+    /// 数据完整性校验失败
+    INTEGRITY = 20004,
+}
+
+impl ErrNo {
+    /// 根据http状态码获取错误码
+    /// # Examples
+    /// ```
+    /// use qcos::request::ErrNo;
+    /// use reqwest::StatusCode;
+    /// assert_eq!(ErrNo::from_status(StatusCode::OK), ErrNo::SUCCESS);
+    /// assert_eq!(ErrNo::from_status(StatusCode::NOT_MODIFIED), ErrNo::NOT_MODIFIED);
+    /// assert_eq!(ErrNo::from_status(StatusCode::PRECONDITION_FAILED), ErrNo::PRECONDITION_FAILED);
+    /// assert_eq!(ErrNo::from_status(StatusCode::NOT_FOUND), ErrNo::STATUS);
+    /// ```
+    pub fn from_status(status_code: reqwest::StatusCode) -> Self {
+        match status_code {
+            reqwest::StatusCode::NOT_MODIFIED => ErrNo::NOT_MODIFIED,
+            reqwest::StatusCode::PRECONDITION_FAILED => ErrNo::PRECONDITION_FAILED,
+            x if x.is_client_error() || x.is_server_error() => ErrNo::STATUS,
+            _ => ErrNo::SUCCESS,
+        }
+    }
 }
 
 /// 请求方法
@@ -71,6 +519,7 @@ pub enum Method {
     Delete,
     Put,
     Head,
+    Options,
 }
 
 /// # Examples
@@ -84,6 +533,21 @@ impl Display for ErrNo {
     }
 }
 
+/// 接口返回的错误信息
+/// <https://cloud.tencent.com/document/product/436/7730>
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Default)]
+pub struct ErrorResponse {
+    /// 错误码，如`NoSuchBucket`、`BucketAlreadyOwnedByYou`
+    #[serde(rename = "Code")]
+    pub code: String,
+    #[serde(rename = "Message", default)]
+    pub message: String,
+    #[serde(rename = "RequestId", default)]
+    pub request_id: String,
+    #[serde(rename = "TraceId", default)]
+    pub trace_id: String,
+}
+
 /// http请求返回类型，无论成功还是失败都返回该类型，根据`error_no`可区分是否成功
 #[derive(Debug, Clone)]
 pub struct Response {
@@ -95,6 +559,8 @@ pub struct Response {
     pub result: Vec<u8>,
     /// 接口返回的headers, 有些接口需要拿到头部信息进行校验
     pub headers: HashMap<String, String>,
+    /// 接口返回的http状态码，请求未发出时为`None`
+    pub status: Option<u16>,
 }
 
 impl From<reqwest::Error> for Response {
@@ -112,6 +578,7 @@ impl From<reqwest::Error> for Response {
             error_message: value.to_string(),
             result: Vec::new(),
             headers: HashMap::new(),
+            status: value.status().map(|x| x.as_u16()),
         }
     }
 }
@@ -135,6 +602,7 @@ impl Default for Response {
             error_message: Default::default(),
             result: Default::default(),
             headers: Default::default(),
+            status: None,
         }
     }
 }
@@ -146,6 +614,7 @@ impl Response {
             error_message,
             result,
             headers: HashMap::new(),
+            status: None,
         }
     }
     pub fn data_success(result: Vec<u8>) -> Self {
@@ -154,7 +623,40 @@ impl Response {
             error_message: Default::default(),
             result,
             headers: Default::default(),
+            status: None,
+        }
+    }
+    /// 存储桶开启版本控制时，上传、复制、删除对象返回的版本ID
+    pub fn version_id(&self) -> Option<&str> {
+        self.headers.get("x-cos-version-id").map(|x| x.as_str())
+    }
+
+    /// 接口返回的http状态码，如`404`，请求未发出时返回`None`
+    /// # Examples
+    /// ```
+    /// use qcos::request::{ErrNo, Response};
+    /// let mut resp = Response::new(ErrNo::STATUS, "404 Not Found".to_string(), Vec::new());
+    /// resp.status = Some(404);
+    /// assert_eq!(resp.status_code(), Some(404));
+    /// assert_eq!(Response::default().status_code(), None);
+    /// ```
+    pub fn status_code(&self) -> Option<u16> {
+        self.status
+    }
+
+    /// 解析接口返回的错误信息，HEAD请求或返回内容不是错误信息时返回`None`
+    /// # Examples
+    /// ```
+    /// use qcos::request::{ErrNo, Response};
+    /// let body = "<Error><Code>NoSuchBucket</Code><Message>The specified bucket does not exist.</Message><RequestId>NjQ</RequestId></Error>";
+    /// let resp = Response::new(ErrNo::STATUS, "404 Not Found".to_string(), body.into());
+    /// assert_eq!(resp.error_response().unwrap().code, "NoSuchBucket");
+    /// ```
+    pub fn error_response(&self) -> Option<ErrorResponse> {
+        if self.error_no == ErrNo::SUCCESS || self.result.is_empty() {
+            return None;
         }
+        quick_xml::de::from_reader(&self.result[..]).ok()
     }
 }
 
@@ -198,6 +700,23 @@ impl Request {
         )
         .await
     }
+    /// send options request
+    pub async fn options(
+        url: &str,
+        query: Option<&HashMap<String, String>>,
+        headers: Option<&HeaderMap>,
+    ) -> Result<Response, Response> {
+        Request::do_req(
+            Method::Options,
+            url,
+            query,
+            headers,
+            None,
+            None,
+            None as Option<Body>,
+        )
+        .await
+    }
     /// send get request
     /// # Examples
     /// ```
@@ -311,6 +830,7 @@ impl Request {
             Method::Post => client.post(url),
             Method::Put => client.put(url),
             Method::Head => client.head(url),
+            Method::Options => client.request(reqwest::Method::OPTIONS, url),
         };
         if let Some(v) = query {
             req = req.query(v);
@@ -326,10 +846,9 @@ impl Request {
         }
         let resp = req.send().await?;
         let status_code = resp.status();
-        let mut error_no = ErrNo::SUCCESS;
+        let error_no = ErrNo::from_status(status_code);
         let mut message = String::new();
-        if status_code.is_client_error() || status_code.is_server_error() {
-            error_no = ErrNo::STATUS;
+        if error_no != ErrNo::SUCCESS {
             message = status_code.to_string();
         }
         let mut headers = HashMap::new();
@@ -341,6 +860,7 @@ impl Request {
             error_message: message,
             result: resp.bytes().await?.to_vec(),
             headers,
+            status: Some(status_code.as_u16()),
         })
     }
 }
@@ -0,0 +1,265 @@
+//! 恢复归档对象
+//!
+//! 存储类型为`ARCHIVE`、`DEEP_ARCHIVE`的对象不能直接读取，需要先调用[`Client::restore_object`]
+//! 恢复出一个临时副本，恢复完成后在有效期内可以正常下载。
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use reqwest::Body;
+use tokio::time::Instant;
+
+use crate::client::Client;
+use crate::objects::StorageClassEnum;
+use crate::request::{ErrNo, Request, Response};
+
+/// 恢复模式，不同模式的恢复耗时不同
+/// <https://cloud.tencent.com/document/product/436/12633>
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Copy)]
+pub enum RestoreTier {
+    /// 极速模式，仅支持`ARCHIVE`
+    Expedited,
+    /// 标准模式
+    Standard,
+    /// 批量模式
+    Bulk,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+struct CasJobParameters {
+    #[serde(rename = "Tier")]
+    tier: RestoreTier,
+}
+
+/// 恢复归档对象的请求体
+/// ```
+/// use qcos::restore::{RestoreRequest, RestoreTier};
+/// use quick_xml::se::to_string;
+/// let s = to_string(&RestoreRequest::new(1, RestoreTier::Standard)).unwrap();
+/// assert_eq!(s, "<RestoreRequest><Days>1</Days><CASJobParameters><Tier>Standard</Tier></CASJobParameters></RestoreRequest>");
+/// ```
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct RestoreRequest {
+    #[serde(rename = "Days")]
+    days: u32,
+    #[serde(rename = "CASJobParameters")]
+    cas_job_parameters: CasJobParameters,
+}
+
+impl RestoreRequest {
+    pub fn new(days: u32, tier: RestoreTier) -> Self {
+        Self {
+            days,
+            cas_job_parameters: CasJobParameters { tier },
+        }
+    }
+}
+
+/// 归档对象的恢复状态，取自响应头`x-cos-restore`
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum RestoreStatus {
+    /// 正在恢复
+    Ongoing,
+    /// 已恢复，临时副本在`expiry_date`之后失效
+    Completed { expiry_date: Option<DateTime<Utc>> },
+}
+
+/// 恢复状态的响应头
+pub const RESTORE_HEADER: &str = "x-cos-restore";
+
+/// 取出`name="value"`中的`value`
+fn get_quoted_value<'a>(s: &'a str, name: &str) -> Option<&'a str> {
+    let start = s.find(&format!("{}=\"", name))? + name.len() + 2;
+    let end = s[start..].find('"')? + start;
+    Some(&s[start..end])
+}
+
+/// # Examples
+/// ```
+/// use qcos::restore::RestoreStatus;
+/// let status: RestoreStatus = r#"ongoing-request="true""#.parse().unwrap();
+/// assert_eq!(status, RestoreStatus::Ongoing);
+/// let status: RestoreStatus = r#"ongoing-request="false", expiry-date="Thu, 17 Sep 2020 09:30:00 GMT""#.parse().unwrap();
+/// match status {
+///     RestoreStatus::Completed { expiry_date } => {
+///         assert_eq!(expiry_date.unwrap().to_rfc3339(), "2020-09-17T09:30:00+00:00");
+///     }
+///     _ => unreachable!(),
+/// }
+/// ```
+impl FromStr for RestoreStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match get_quoted_value(s, "ongoing-request") {
+            Some("true") => Ok(RestoreStatus::Ongoing),
+            Some("false") => Ok(RestoreStatus::Completed {
+                expiry_date: get_quoted_value(s, "expiry-date")
+                    .and_then(|x| DateTime::parse_from_rfc2822(x).ok())
+                    .map(|x| x.with_timezone(&Utc)),
+            }),
+            _ => Err(format!("无法解析的恢复状态: {}", s)),
+        }
+    }
+}
+
+impl RestoreStatus {
+    /// 从响应头中解析恢复状态，未发起过恢复时返回`None`
+    pub fn from_headers(headers: &HashMap<String, String>) -> Option<Self> {
+        headers.get(RESTORE_HEADER).and_then(|x| x.parse().ok())
+    }
+}
+
+impl Client {
+    /// 恢复归档对象，恢复完成后临时副本在`days`天内可以读取
+    /// <https://cloud.tencent.com/document/product/436/12633>
+    /// 首次发起返回202，已恢复过的对象再次调用会延长有效期并返回200
+    /// # 参数
+    /// - key: 对象的key
+    /// - days: 临时副本的有效天数
+    /// - tier: 恢复模式
+    ///
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// use qcos::restore::RestoreTier;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let res = client.restore_object("Cargo.toml", 1, RestoreTier::Standard).await;
+    /// };
+    /// ```
+    pub async fn restore_object(&self, key: &str, days: u32, tier: RestoreTier) -> Response {
+        let body = match quick_xml::se::to_string(&RestoreRequest::new(days, tier)) {
+            Ok(s) => s,
+            Err(e) => return Response::new(ErrNo::ENCODE, e.to_string(), Default::default()),
+        };
+        let url_path = self.get_path_from_object_key(key);
+        let mut query = HashMap::new();
+        query.insert("restore".to_string(), String::new());
+        let mut headers = self.get_common_headers();
+        headers.insert(
+            reqwest::header::CONTENT_TYPE,
+            reqwest::header::HeaderValue::from_str("application/xml").unwrap(),
+        );
+        let headers = self.get_headers_with_auth(
+            "post",
+            url_path.as_str(),
+            None,
+            Some(headers),
+            Some(query.clone()),
+        );
+        let resp = Request::post(
+            self.get_full_url_from_path(url_path.as_str()).as_str(),
+            Some(&query),
+            Some(&headers),
+            None,
+            None,
+            Some(Body::from(body)),
+        )
+        .await;
+        self.make_response(resp)
+    }
+
+    /// 查询归档对象的恢复状态，未发起过恢复时返回`Ok(None)`
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// use qcos::restore::RestoreStatus;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// if let Ok(Some(RestoreStatus::Ongoing)) = client.get_restore_status("Cargo.toml").await {
+    ///     println!("正在恢复");
+    /// }
+    /// };
+    /// ```
+    pub async fn get_restore_status(&self, key: &str) -> Result<Option<RestoreStatus>, Response> {
+        let resp = self.head_object(key).await;
+        if resp.error_no != ErrNo::SUCCESS {
+            return Err(resp);
+        }
+        Ok(RestoreStatus::from_headers(&resp.headers))
+    }
+
+    /// 每隔`interval`查询一次，直到对象可以读取，返回`Ok(())`表示可以下载
+    /// - 非归档对象直接返回
+    /// - 归档对象未发起过恢复时返回`ErrNo::PARAM`，需要先调用[`restore_object()`]
+    /// - 超过`timeout`仍未恢复完成返回`ErrNo::OTHER`，不传表示一直等待
+    ///
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// use qcos::restore::RestoreTier;
+    /// use std::time::Duration;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// client.restore_object("Cargo.toml", 1, RestoreTier::Expedited).await;
+    /// if client.wait_for_restore("Cargo.toml", Duration::from_secs(60), None).await.is_ok() {
+    ///     let res = client.get_object("Cargo.toml", "Cargo.toml", None).await;
+    /// }
+    /// };
+    /// ```
+    ///
+    /// [`restore_object()`]: Self::restore_object
+    pub async fn wait_for_restore(
+        &self,
+        key: &str,
+        interval: Duration,
+        timeout: Option<Duration>,
+    ) -> Result<(), Response> {
+        let start = Instant::now();
+        loop {
+            let resp = self.head_object(key).await;
+            if resp.error_no != ErrNo::SUCCESS {
+                return Err(resp);
+            }
+            match RestoreStatus::from_headers(&resp.headers) {
+                Some(RestoreStatus::Completed { .. }) => return Ok(()),
+                Some(RestoreStatus::Ongoing) => {}
+                None => {
+                    if !StorageClassEnum::from_headers(&resp.headers).is_archive() {
+                        return Ok(());
+                    }
+                    return Err(Response::new(
+                        ErrNo::PARAM,
+                        format!("归档对象{}未发起恢复", key),
+                        Default::default(),
+                    ));
+                }
+            }
+            if let Some(timeout) = timeout {
+                if start.elapsed() + interval > timeout {
+                    return Err(Response::new(
+                        ErrNo::OTHER,
+                        format!("等待对象{}恢复超时", key),
+                        Default::default(),
+                    ));
+                }
+            }
+            tokio::time::sleep(interval).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::restore::{RestoreStatus, RESTORE_HEADER};
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_restore_status() {
+        let mut headers = HashMap::new();
+        assert_eq!(RestoreStatus::from_headers(&headers), None);
+        headers.insert(
+            RESTORE_HEADER.to_string(),
+            r#"ongoing-request="false""#.to_string(),
+        );
+        assert_eq!(
+            RestoreStatus::from_headers(&headers),
+            Some(RestoreStatus::Completed { expiry_date: None })
+        );
+        headers.insert(RESTORE_HEADER.to_string(), "unknown".to_string());
+        assert_eq!(RestoreStatus::from_headers(&headers), None);
+        assert!("ongoing-request=".parse::<RestoreStatus>().is_err());
+    }
+}
@@ -1,14 +1,92 @@
 //! 查询bucket列表 方法见 [`crate::client::Client`#impl-Client-3]
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
 use reqwest::header::{HeaderValue, HOST};
 
 use crate::client::Client;
 use crate::request::Request;
-use crate::request::Response;
+use crate::request::{Owner, Response};
+use crate::tagging::Tag;
 
 // 为了兼容以前的版本
 pub struct Service;
 
+/// 存储桶信息
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct BucketInfo {
+    /// 存储桶名称，格式为`<BucketName-APPID>`
+    #[serde(rename = "Name")]
+    pub name: String,
+    /// 存储桶所在地域
+    #[serde(rename = "Location")]
+    pub location: String,
+    #[serde(rename = "CreationDate")]
+    pub creation_date: DateTime<Utc>,
+    /// 存储桶类型，如`cos`、`ofs`
+    #[serde(rename = "BucketType", default)]
+    pub bucket_type: String,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Default)]
+pub struct Buckets {
+    #[serde(rename = "Bucket", default)]
+    pub bucket: Vec<BucketInfo>,
+}
+
+/// 存储桶列表
+/// ```
+/// use qcos::service::ListAllMyBucketsResult;
+/// use quick_xml::de::from_str;
+/// let s = "<ListAllMyBucketsResult><Owner><ID>qcs::cam::uin/100000000001:uin/100000000001</ID><DisplayName>100000000001</DisplayName></Owner><Buckets><Bucket><Name>examplebucket-1250000000</Name><Location>ap-guangzhou</Location><CreationDate>2019-05-24T11:49:50Z</CreationDate><BucketType>cos</BucketType></Bucket></Buckets><Marker/><MaxKeys>1</MaxKeys><IsTruncated>true</IsTruncated><NextMarker>examplebucket-1250000000</NextMarker></ListAllMyBucketsResult>";
+/// let res: ListAllMyBucketsResult = from_str(s).unwrap();
+/// assert_eq!(res.buckets.bucket[0].location, "ap-guangzhou");
+/// assert!(res.is_truncated);
+/// assert_eq!(res.next_marker, "examplebucket-1250000000");
+/// ```
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Default)]
+pub struct ListAllMyBucketsResult {
+    #[serde(rename = "Owner", default)]
+    pub owner: Owner,
+    #[serde(rename = "Buckets", default)]
+    pub buckets: Buckets,
+    #[serde(rename = "Marker", default)]
+    pub marker: String,
+    #[serde(rename = "MaxKeys", default)]
+    pub max_keys: u64,
+    /// 是否还有未返回的结果，为`true`时使用`next_marker`继续查询
+    #[serde(rename = "IsTruncated", default)]
+    pub is_truncated: bool,
+    #[serde(rename = "NextMarker", default)]
+    pub next_marker: String,
+}
+
 impl Client {
+    /// 创建不指定存储桶及地域的Client，用于查询请求者名下的全部存储桶
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// let client = Client::service("secrect_id", "secrect_key");
+    /// assert_eq!(client.get_host_for_bucket_query(), "service.cos.myqcloud.com");
+    /// ```
+    pub fn service(secrect_id: impl Into<String>, secrect_key: impl Into<String>) -> Self {
+        Self::new(secrect_id, secrect_key, "", "")
+    }
+
+    async fn get_service(&self, query: HashMap<String, String>) -> Response {
+        let host = self.get_host_for_bucket_query();
+        let mut headers = self.get_common_headers();
+        headers.insert(HOST, HeaderValue::from_str(&host).unwrap());
+        headers = self.get_headers_with_auth("get", "/", None, Some(headers), Some(query.clone()));
+        let resp = Request::get(
+            format!("https://{}/", host).as_str(),
+            Some(&query),
+            Some(&headers),
+        )
+        .await;
+        self.make_response(resp)
+    }
+
     /**
     查询请求者名下的所有存储桶列表或特定地域下的存储桶列表
     见[文档](https://cloud.tencent.com/document/product/436/8291)
@@ -23,16 +101,63 @@ impl Client {
     ```
     */
     pub async fn get_bucket_list(&self) -> Response {
-        let host = self.get_host_for_bucket_query();
-        let mut headers = self.get_common_headers();
-        headers.insert(HOST, HeaderValue::from_str(&host).unwrap());
-        headers = self.get_headers_with_auth("get", "/", None, Some(headers), None);
-        let resp = Request::get(
-            format!("https://{}/", self.get_host_for_bucket_query()).as_str(),
-            None,
-            Some(&headers),
-        )
-        .await;
-        self.make_response(resp)
+        self.get_service(HashMap::new()).await
+    }
+
+    /// 分页查询存储桶列表，返回解析后的结果
+    /// <https://cloud.tencent.com/document/product/436/8291>
+    /// # 参数
+    /// - region: 只返回该地域的存储桶，为空时不筛选
+    /// - tag: 只返回带有该标签的存储桶
+    /// - marker: 从该存储桶之后开始列出，首次查询为空，之后使用结果中的`next_marker`
+    /// - max_keys: 最大返回条数，1-2000之间，其他值使用默认值
+    ///
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// async {
+    /// let client = Client::service("foo", "bar");
+    /// let mut marker = String::new();
+    /// loop {
+    ///     let res = match client.list_buckets("ap-guangzhou", None, &marker, 100).await {
+    ///         Ok(res) => res,
+    ///         Err(e) => {
+    ///             assert!(e.error_message.contains("403"));
+    ///             break;
+    ///         }
+    ///     };
+    ///     for bucket in res.buckets.bucket {
+    ///         println!("{} {} {}", bucket.name, bucket.location, bucket.creation_date);
+    ///     }
+    ///     if !res.is_truncated {
+    ///         break;
+    ///     }
+    ///     marker = res.next_marker;
+    /// }
+    /// };
+    /// ```
+    pub async fn list_buckets(
+        &self,
+        region: &str,
+        tag: Option<&Tag>,
+        marker: &str,
+        max_keys: u64,
+    ) -> Result<ListAllMyBucketsResult, Response> {
+        let mut query = HashMap::new();
+        if !region.is_empty() {
+            query.insert("region".to_string(), region.to_string());
+        }
+        if let Some(tag) = tag {
+            query.insert("tagkey".to_string(), tag.key.clone());
+            query.insert("tagvalue".to_string(), tag.value.clone());
+        }
+        if !marker.is_empty() {
+            query.insert("marker".to_string(), marker.to_string());
+        }
+        if max_keys > 0 && max_keys <= 2000 {
+            query.insert("maxkeys".to_string(), max_keys.to_string());
+        }
+        let resp = self.get_service(query).await;
+        self.make_xml_response(Ok(resp))
     }
 }
@@ -0,0 +1,335 @@
+//! 标签
+//!
+//! 对象标签可用于计费分账及生命周期规则的筛选，上传时可以通过
+//! [`ObjectOptions::insert_tagging`](crate::options::ObjectOptions::insert_tagging)直接设置；
+//! 存储桶标签用于按项目、团队等维度管理存储桶。
+use std::collections::{HashMap, HashSet};
+
+use crate::client::Client;
+use crate::request::{ErrNo, Request, Response};
+
+/// 单个对象最多10个标签
+pub const OBJECT_MAX_TAGS: usize = 10;
+
+/// 单个存储桶最多50个标签
+pub const BUCKET_MAX_TAGS: usize = 50;
+
+/// 标签键最长128个字符
+pub const TAG_KEY_MAX_LEN: usize = 128;
+
+/// 标签值最长256个字符
+pub const TAG_VALUE_MAX_LEN: usize = 256;
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone)]
+pub struct Tag {
+    #[serde(rename = "Key")]
+    pub key: String,
+    #[serde(rename = "Value", default)]
+    pub value: String,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Default)]
+pub struct TagSet {
+    #[serde(rename = "Tag", default)]
+    pub tag: Vec<Tag>,
+}
+
+/// 标签集合
+/// <https://cloud.tencent.com/document/product/436/42997>
+/// ```
+/// use qcos::tagging::Tagging;
+/// use quick_xml::de::from_str;
+/// use quick_xml::se::to_string;
+/// let mut tagging = Tagging::new();
+/// tagging.add_tag("team", "storage").add_tag("retention", "30d");
+/// let s = to_string(&tagging).unwrap();
+/// assert_eq!(s, "<Tagging><TagSet><Tag><Key>team</Key><Value>storage</Value></Tag><Tag><Key>retention</Key><Value>30d</Value></Tag></TagSet></Tagging>");
+/// assert_eq!(from_str::<Tagging>(&s).unwrap(), tagging);
+/// assert_eq!(tagging.get("team"), Some("storage"));
+/// assert_eq!(from_str::<Tagging>("<Tagging><TagSet/></Tagging>").unwrap(), Tagging::new());
+/// ```
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Default)]
+pub struct Tagging {
+    #[serde(rename = "TagSet", default)]
+    pub tag_set: TagSet,
+}
+
+impl Tagging {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 添加一个标签，键重复时覆盖原来的值
+    pub fn add_tag(&mut self, key: &str, value: &str) -> &mut Self {
+        match self.tag_set.tag.iter_mut().find(|x| x.key == key) {
+            Some(tag) => tag.value = value.to_string(),
+            None => self.tag_set.tag.push(Tag {
+                key: key.to_string(),
+                value: value.to_string(),
+            }),
+        }
+        self
+    }
+
+    /// 获取标签的值
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.tag_set
+            .tag
+            .iter()
+            .find(|x| x.key == key)
+            .map(|x| x.value.as_str())
+    }
+
+    pub fn tags(&self) -> &[Tag] {
+        &self.tag_set.tag
+    }
+
+    /// 转为`HashMap`
+    pub fn to_map(&self) -> HashMap<String, String> {
+        self.tag_set
+            .tag
+            .iter()
+            .map(|x| (x.key.clone(), x.value.clone()))
+            .collect()
+    }
+
+    /// 校验标签数量不超过`max_tags`，键非空且不重复，键值长度不超过限制，不满足时返回`ErrNo::PARAM`
+    /// # Examples
+    /// ```
+    /// use qcos::tagging::{Tagging, OBJECT_MAX_TAGS};
+    /// use qcos::request::ErrNo;
+    /// let mut tagging = Tagging::new();
+    /// for i in 0..=OBJECT_MAX_TAGS {
+    ///     tagging.add_tag(&format!("key{}", i), "value");
+    /// }
+    /// assert_eq!(tagging.validate(OBJECT_MAX_TAGS).unwrap_err().error_no, ErrNo::PARAM);
+    /// ```
+    pub fn validate(&self, max_tags: usize) -> Result<(), Response> {
+        let error = |message: String| Err(Response::new(ErrNo::PARAM, message, Vec::new()));
+        if self.tag_set.tag.len() > max_tags {
+            return error(format!(
+                "标签数量{}超过上限{}",
+                self.tag_set.tag.len(),
+                max_tags
+            ));
+        }
+        let mut keys = HashSet::new();
+        for tag in &self.tag_set.tag {
+            let key_len = tag.key.chars().count();
+            if key_len == 0 || key_len > TAG_KEY_MAX_LEN {
+                return error(format!(
+                    "标签键长度需在1-{}之间: {}",
+                    TAG_KEY_MAX_LEN, tag.key
+                ));
+            }
+            if tag.value.chars().count() > TAG_VALUE_MAX_LEN {
+                return error(format!(
+                    "标签值长度不能超过{}: {}",
+                    TAG_VALUE_MAX_LEN, tag.key
+                ));
+            }
+            if !keys.insert(tag.key.as_str()) {
+                return error(format!("标签键重复: {}", tag.key));
+            }
+        }
+        Ok(())
+    }
+
+    /// 生成上传时`x-cos-tagging`请求头的值，如`team=storage&retention=30d`
+    /// # Examples
+    /// ```
+    /// use qcos::tagging::Tagging;
+    /// let mut tagging = Tagging::new();
+    /// tagging.add_tag("team", "云存储").add_tag("a&b", "");
+    /// assert_eq!(tagging.to_header_value(), "team=%E4%BA%91%E5%AD%98%E5%82%A8&a%26b=");
+    /// ```
+    pub fn to_header_value(&self) -> String {
+        self.tag_set
+            .tag
+            .iter()
+            .map(|x| {
+                format!(
+                    "{}={}",
+                    urlencoding::encode(&x.key),
+                    urlencoding::encode(&x.value)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("&")
+    }
+}
+
+impl Client {
+    /// 设置对象的标签，会覆盖已有的标签
+    /// <https://cloud.tencent.com/document/product/436/42997>
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// use qcos::tagging::Tagging;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let mut tagging = Tagging::new();
+    /// tagging.add_tag("team", "storage");
+    /// let res = client.put_object_tagging("Cargo.toml", &tagging).await;
+    /// };
+    /// ```
+    pub async fn put_object_tagging(&self, key: &str, tagging: &Tagging) -> Response {
+        if let Err(e) = tagging.validate(OBJECT_MAX_TAGS) {
+            return e;
+        }
+        self.put_object_config(key, "tagging", tagging).await
+    }
+
+    /// 查询对象的标签
+    /// <https://cloud.tencent.com/document/product/436/42998>
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// if let Ok(tagging) = client.get_object_tagging("Cargo.toml").await {
+    ///     println!("{:?}", tagging.to_map());
+    /// }
+    /// };
+    /// ```
+    pub async fn get_object_tagging(&self, key: &str) -> Result<Tagging, Response> {
+        self.get_object_config(key, "tagging").await
+    }
+
+    /// 删除对象的全部标签
+    /// <https://cloud.tencent.com/document/product/436/42999>
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let res = client.delete_object_tagging("Cargo.toml").await;
+    /// };
+    /// ```
+    pub async fn delete_object_tagging(&self, key: &str) -> Response {
+        let url_path = self.get_path_from_object_key(key);
+        let mut query = HashMap::new();
+        query.insert("tagging".to_string(), String::new());
+        let headers = self.get_headers_with_auth(
+            "delete",
+            url_path.as_str(),
+            None,
+            None,
+            Some(query.clone()),
+        );
+        let resp = Request::delete(
+            self.get_full_url_from_path(url_path.as_str()).as_str(),
+            Some(&query),
+            Some(&headers),
+            None,
+            None,
+        )
+        .await;
+        self.make_response(resp)
+    }
+
+    /// 设置存储桶的标签，会覆盖已有的标签
+    /// <https://cloud.tencent.com/document/product/436/34838>
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// use qcos::tagging::Tagging;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let mut tagging = Tagging::new();
+    /// tagging.add_tag("team", "storage");
+    /// let res = client.put_bucket_tagging(&tagging).await;
+    /// assert!(res.error_message.contains("403"));
+    /// };
+    /// ```
+    pub async fn put_bucket_tagging(&self, tagging: &Tagging) -> Response {
+        if let Err(e) = tagging.validate(BUCKET_MAX_TAGS) {
+            return e;
+        }
+        self.put_bucket_config("tagging", tagging).await
+    }
+
+    /// 查询存储桶的标签，未设置时返回404
+    /// <https://cloud.tencent.com/document/product/436/34837>
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// if let Ok(tagging) = client.get_bucket_tagging().await {
+    ///     println!("{:?}", tagging.get("team"));
+    /// }
+    /// };
+    /// ```
+    pub async fn get_bucket_tagging(&self) -> Result<Tagging, Response> {
+        self.get_bucket_config("tagging").await
+    }
+
+    /// 删除存储桶的全部标签
+    /// <https://cloud.tencent.com/document/product/436/34836>
+    /// # Examples
+    /// ```
+    /// use qcos::client::Client;
+    /// async {
+    /// let client = Client::new("foo", "bar", "qcloudtest-xxx", "ap-guangzhou");
+    /// let res = client.delete_bucket_tagging().await;
+    /// assert!(res.error_message.contains("403"));
+    /// };
+    /// ```
+    pub async fn delete_bucket_tagging(&self) -> Response {
+        self.delete_bucket_config("tagging").await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::request::ErrNo;
+    use crate::tagging::{
+        Tag, Tagging, BUCKET_MAX_TAGS, OBJECT_MAX_TAGS, TAG_KEY_MAX_LEN, TAG_VALUE_MAX_LEN,
+    };
+
+    #[test]
+    fn test_validate() {
+        let mut tagging = Tagging::new();
+        tagging.add_tag("team", "storage").add_tag("team", "cos");
+        assert_eq!(tagging.tags().len(), 1);
+        assert!(tagging.validate(OBJECT_MAX_TAGS).is_ok());
+        assert!(Tagging::new().validate(OBJECT_MAX_TAGS).is_ok());
+
+        let mut tagging = Tagging::new();
+        tagging.add_tag(&"k".repeat(TAG_KEY_MAX_LEN + 1), "");
+        assert_eq!(
+            tagging.validate(OBJECT_MAX_TAGS).unwrap_err().error_no,
+            ErrNo::PARAM
+        );
+
+        let mut tagging = Tagging::new();
+        // 按字符计算长度
+        tagging.add_tag("k", &"值".repeat(TAG_VALUE_MAX_LEN));
+        assert!(tagging.validate(OBJECT_MAX_TAGS).is_ok());
+        tagging.add_tag("k", &"值".repeat(TAG_VALUE_MAX_LEN + 1));
+        assert!(tagging.validate(OBJECT_MAX_TAGS).is_err());
+
+        let mut tagging = Tagging::new();
+        tagging.add_tag("", "v");
+        assert!(tagging.validate(OBJECT_MAX_TAGS).is_err());
+
+        // 存储桶允许的标签数量更多
+        let mut tagging = Tagging::new();
+        for i in 0..BUCKET_MAX_TAGS {
+            tagging.add_tag(&i.to_string(), "v");
+        }
+        assert!(tagging.validate(OBJECT_MAX_TAGS).is_err());
+        assert!(tagging.validate(BUCKET_MAX_TAGS).is_ok());
+
+        // 直接修改字段可能产生重复的键
+        let mut tagging = Tagging::new();
+        for _ in 0..2 {
+            tagging.tag_set.tag.push(Tag {
+                key: "k".to_string(),
+                value: "v".to_string(),
+            });
+        }
+        assert!(tagging.validate(OBJECT_MAX_TAGS).is_err());
+    }
+}